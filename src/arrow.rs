@@ -0,0 +1,514 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Bridge between this crate's dynamically-typed [`Value`]/[`ValueSchema`] and the
+//! Arrow columnar format, for zero-friction handoff to the Arrow/DataFusion ecosystem.
+//!
+//! [`ValueSchema::to_arrow_datatype`] maps each `ValueSchema` variant, i.e. the choice
+//! `Record::parse` already made from a column's logical type (and `isAdjustedToUTC` for
+//! timestamps), to an Arrow `DataType`, so the two mappings stay in lockstep: whatever
+//! `Value` variant `Record::parse` chose for a column, this module picks the Arrow
+//! `DataType` that actually holds it.
+//!
+//! [`read_to_record_batches`] sits on top of this and turns a stream of row-wise [`Group`]s
+//! into `batch_size`-sized [`RecordBatch`]es, so a caller iterating this schema's
+//! `Record::reader` doesn't have to chunk rows into batches itself.
+
+use std::sync::Arc;
+
+use arrow::{
+    array::{
+        ArrayBuilder, ArrayRef, BinaryBuilder, BooleanBuilder, Date32Builder,
+        Decimal128Builder, Float32Builder, Float64Builder, Int16Builder, Int32Builder,
+        Int64Builder, Int8Builder, IntervalMonthDayNanoBuilder, ListBuilder,
+        StringBuilder, StructBuilder, Time32MillisecondBuilder, Time64MicrosecondBuilder,
+        TimestampMicrosecondBuilder, TimestampMillisecondBuilder,
+        TimestampNanosecondBuilder, UInt16Builder, UInt32Builder, UInt64Builder,
+        UInt8Builder,
+    },
+    datatypes::{
+        DataType, Field, Fields, IntervalMonthDayNanoType, IntervalUnit, Schema, TimeUnit,
+    },
+    record_batch::RecordBatch,
+};
+
+use crate::{
+    errors::{ParquetError, Result},
+    record::{
+        schemas::{DecimalSchema, GroupSchema, TimeSchema, TimestampSchema, ValueSchema},
+        types::{Group, Value},
+    },
+};
+
+impl ValueSchema {
+    /// Maps this schema to the Arrow [`DataType`] that [`GroupBatchBuilder`] will
+    /// materialize `Value`s matching it into.
+    pub fn to_arrow_datatype(&self) -> Result<DataType> {
+        Ok(match self {
+            ValueSchema::Bool(_) => DataType::Boolean,
+            ValueSchema::U8(_) => DataType::UInt8,
+            ValueSchema::I8(_) => DataType::Int8,
+            ValueSchema::U16(_) => DataType::UInt16,
+            ValueSchema::I16(_) => DataType::Int16,
+            ValueSchema::U32(_) => DataType::UInt32,
+            ValueSchema::I32(_) => DataType::Int32,
+            ValueSchema::U64(_) => DataType::UInt64,
+            ValueSchema::I64(_) => DataType::Int64,
+            ValueSchema::F32(_) => DataType::Float32,
+            ValueSchema::F64(_) => DataType::Float64,
+            ValueSchema::Date(_) => DataType::Date32,
+            ValueSchema::Time(TimeSchema::Millis) => {
+                DataType::Time32(TimeUnit::Millisecond)
+            }
+            ValueSchema::Time(TimeSchema::Micros) => {
+                DataType::Time64(TimeUnit::Microsecond)
+            }
+            ValueSchema::TimeWithoutTimezone(_) => {
+                // Carries no UTC offset, but Arrow has no "local time" distinction for
+                // `Time32`/`Time64`; both its millisecond and microsecond encodings map to
+                // `Time64(Microsecond)` (unlike `Time`, whose millisecond encoding maps to
+                // `Time32`).
+                DataType::Time64(TimeUnit::Microsecond)
+            }
+            ValueSchema::Timestamp(TimestampSchema::Millis) => {
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+            }
+            ValueSchema::Timestamp(TimestampSchema::Micros) => {
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+            }
+            ValueSchema::Timestamp(TimestampSchema::Int96) => {
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+            }
+            ValueSchema::DateTimeWithoutTimezone(_) => {
+                // No UTC offset: Arrow's unzoned `Timestamp` (`tz: None`) is the local-time
+                // equivalent.
+                DataType::Timestamp(TimeUnit::Microsecond, None)
+            }
+            ValueSchema::DateWithoutTimezone(_) => DataType::Date32,
+            ValueSchema::Timezone(_) => DataType::Utf8,
+            // Closest of Arrow's three `IntervalUnit`s to months+days+millis: the other
+            // two each drop a component (`YearMonth` has no days, `DayTime` no months).
+            ValueSchema::Interval(_) => DataType::Interval(IntervalUnit::MonthDayNano),
+            ValueSchema::Decimal(DecimalSchema::Int32 { precision, scale })
+            | ValueSchema::Decimal(DecimalSchema::Int64 { precision, scale })
+            | ValueSchema::Decimal(DecimalSchema::Array { precision, scale, .. }) => {
+                DataType::Decimal128(*precision as u8, *scale as i8)
+            }
+            ValueSchema::ByteArray(_) => DataType::Binary,
+            ValueSchema::Bson(_) => DataType::Binary,
+            ValueSchema::String(_)
+            | ValueSchema::Json(_)
+            | ValueSchema::Enum(_)
+            | ValueSchema::IpAddr(_)
+            | ValueSchema::Url(_)
+            | ValueSchema::Webpage(_) => DataType::Utf8,
+            ValueSchema::List(list_schema) => DataType::List(Arc::new(Field::new(
+                "element",
+                list_schema.0.to_arrow_datatype()?,
+                true,
+            ))),
+            ValueSchema::Map(map_schema) => DataType::Map(
+                Arc::new(Field::new(
+                    "entries",
+                    DataType::Struct(Fields::from(vec![
+                        Field::new("key", map_schema.0.to_arrow_datatype()?, false),
+                        Field::new("value", map_schema.1.to_arrow_datatype()?, true),
+                    ])),
+                    false,
+                )),
+                false,
+            ),
+            ValueSchema::Group(group_schema) => {
+                DataType::Struct(group_schema.arrow_fields()?)
+            }
+            ValueSchema::Option(option_schema) => option_schema.0.to_arrow_datatype()?,
+        })
+    }
+}
+
+/// The Arrow [`Field`] a top-level `ValueSchema` bridges to, for columns whose schema
+/// isn't itself a [`GroupSchema`] (and so can't go through [`GroupSchema::to_arrow_schema`]).
+/// Mirrors the per-member `Field`s [`GroupSchema::arrow_fields`] builds, just for a lone
+/// schema rather than a group's fields.
+pub fn value_schema_to_arrow(schema: &ValueSchema) -> Result<Field> {
+    Ok(Field::new("value", schema.to_arrow_datatype()?, true))
+}
+
+impl GroupSchema {
+    fn arrow_fields(&self) -> Result<Fields> {
+        self.1
+            .iter()
+            .map(|(name, &index)| {
+                let schema = &self.0[index];
+                Ok(Field::new(name, schema.to_arrow_datatype()?, true))
+            })
+            .collect::<Result<Vec<_>>>()
+            .map(Fields::from)
+    }
+
+    /// The Arrow `Schema` a [`GroupBatchBuilder`] for this `GroupSchema` will produce
+    /// batches against.
+    pub fn to_arrow_schema(&self) -> Result<Schema> {
+        Ok(Schema::new(self.arrow_fields()?))
+    }
+}
+
+/// Accumulates [`Group`]s (rows) matching a [`GroupSchema`] into Arrow column builders,
+/// flushing them into a [`RecordBatch`] on [`finish`](Self::finish).
+///
+/// `Date`/`Time`/`Timestamp` (and the timezone-less variants of each) columns size and
+/// type their Arrow builder correctly via [`ValueSchema::to_arrow_datatype`], but
+/// [`append`](Self::append) for them isn't wired up yet pending a stable way to pull the
+/// underlying numeric components out of those wrapper types; appending a `Group`
+/// containing one returns an error rather than silently dropping the column.
+/// `Decimal`/`Interval`/`Map` columns, by contrast, are fully supported.
+pub struct GroupBatchBuilder {
+    schema: GroupSchema,
+    arrow_schema: Arc<Schema>,
+    builders: Vec<Box<dyn ArrayBuilder>>,
+}
+
+impl GroupBatchBuilder {
+    /// Creates a builder for `schema`, pre-sizing each column's builder for
+    /// `row_capacity` rows.
+    pub fn try_new(schema: &GroupSchema, row_capacity: usize) -> Result<Self> {
+        let arrow_schema = Arc::new(schema.to_arrow_schema()?);
+        let builders = schema
+            .0
+            .iter()
+            .map(|field_schema| make_builder(field_schema, row_capacity))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { schema: schema.clone(), arrow_schema, builders })
+    }
+
+    /// Appends a row. `group` must have been produced by [`Record::parse`](crate::record::Record::parse)
+    /// against the `Type` this builder's `GroupSchema` was itself derived from.
+    pub fn append(&mut self, group: &Group) -> Result<()> {
+        for (field_schema, (builder, value)) in self
+            .schema
+            .0
+            .iter()
+            .zip(self.builders.iter_mut().zip(group.0.iter()))
+        {
+            append_value(builder.as_mut(), field_schema, value)?;
+        }
+        Ok(())
+    }
+
+    /// Flushes the accumulated rows into a [`RecordBatch`].
+    pub fn finish(mut self) -> Result<RecordBatch> {
+        let columns = self
+            .builders
+            .iter_mut()
+            .map(|builder| builder.finish())
+            .collect::<Vec<ArrayRef>>();
+        RecordBatch::try_new(self.arrow_schema, columns)
+            .map_err(|err| ParquetError::General(format!("Arrow error: {}", err)))
+    }
+}
+
+/// Drives `rows` (as produced by iterating this schema's [`Record::reader`](crate::record::Record::reader))
+/// through a fresh [`GroupBatchBuilder`] every `batch_size` rows, so callers get a stream
+/// of `RecordBatch`es sized the same way Arrow/DataFusion readers expect rather than one
+/// batch holding the whole file.
+pub fn read_to_record_batches<I>(
+    schema: &GroupSchema, batch_size: usize, rows: I,
+) -> Result<Vec<RecordBatch>>
+where
+    I: IntoIterator<Item = Group>,
+{
+    let mut batches = Vec::new();
+    let mut rows = rows.into_iter().peekable();
+    while rows.peek().is_some() {
+        let mut builder = GroupBatchBuilder::try_new(schema, batch_size)?;
+        for group in (&mut rows).take(batch_size) {
+            builder.append(&group)?;
+        }
+        batches.push(builder.finish()?);
+    }
+    Ok(batches)
+}
+
+fn make_builder(schema: &ValueSchema, capacity: usize) -> Result<Box<dyn ArrayBuilder>> {
+    Ok(match schema {
+        ValueSchema::Bool(_) => Box::new(BooleanBuilder::with_capacity(capacity)),
+        ValueSchema::U8(_) => Box::new(UInt8Builder::with_capacity(capacity)),
+        ValueSchema::I8(_) => Box::new(Int8Builder::with_capacity(capacity)),
+        ValueSchema::U16(_) => Box::new(UInt16Builder::with_capacity(capacity)),
+        ValueSchema::I16(_) => Box::new(Int16Builder::with_capacity(capacity)),
+        ValueSchema::U32(_) => Box::new(UInt32Builder::with_capacity(capacity)),
+        ValueSchema::I32(_) => Box::new(Int32Builder::with_capacity(capacity)),
+        ValueSchema::U64(_) => Box::new(UInt64Builder::with_capacity(capacity)),
+        ValueSchema::I64(_) => Box::new(Int64Builder::with_capacity(capacity)),
+        ValueSchema::F32(_) => Box::new(Float32Builder::with_capacity(capacity)),
+        ValueSchema::F64(_) => Box::new(Float64Builder::with_capacity(capacity)),
+        ValueSchema::Date(_) | ValueSchema::DateWithoutTimezone(_) => {
+            Box::new(Date32Builder::with_capacity(capacity))
+        }
+        ValueSchema::Time(TimeSchema::Millis) => {
+            Box::new(Time32MillisecondBuilder::with_capacity(capacity))
+        }
+        ValueSchema::Time(TimeSchema::Micros) | ValueSchema::TimeWithoutTimezone(_) => {
+            Box::new(Time64MicrosecondBuilder::with_capacity(capacity))
+        }
+        ValueSchema::Timestamp(TimestampSchema::Millis) => {
+            Box::new(TimestampMillisecondBuilder::with_capacity(capacity))
+        }
+        ValueSchema::Timestamp(TimestampSchema::Micros)
+        | ValueSchema::DateTimeWithoutTimezone(_) => {
+            Box::new(TimestampMicrosecondBuilder::with_capacity(capacity))
+        }
+        ValueSchema::Timestamp(TimestampSchema::Int96) => {
+            Box::new(TimestampNanosecondBuilder::with_capacity(capacity))
+        }
+        ValueSchema::Interval(_) => {
+            Box::new(IntervalMonthDayNanoBuilder::with_capacity(capacity))
+        }
+        ValueSchema::Decimal(DecimalSchema::Int32 { precision, scale })
+        | ValueSchema::Decimal(DecimalSchema::Int64 { precision, scale })
+        | ValueSchema::Decimal(DecimalSchema::Array { precision, scale, .. }) => {
+            Box::new(
+                Decimal128Builder::with_capacity(capacity)
+                    .with_precision_and_scale(*precision as u8, *scale as i8)
+                    .map_err(|err| ParquetError::General(format!("Arrow error: {}", err)))?,
+            )
+        }
+        ValueSchema::ByteArray(_) | ValueSchema::Bson(_) => {
+            Box::new(BinaryBuilder::with_capacity(capacity, capacity))
+        }
+        ValueSchema::String(_)
+        | ValueSchema::Json(_)
+        | ValueSchema::Enum(_)
+        | ValueSchema::IpAddr(_)
+        | ValueSchema::Url(_)
+        | ValueSchema::Webpage(_)
+        | ValueSchema::Timezone(_) => {
+            Box::new(StringBuilder::with_capacity(capacity, capacity))
+        }
+        ValueSchema::List(list_schema) => Box::new(ListBuilder::with_capacity(
+            make_builder(&list_schema.0, capacity)?,
+            capacity,
+        )),
+        // Arrow represents `Map` as a `List<Struct<key, value>>` under the hood; build
+        // it the same way rather than depending on `MapBuilder`'s key/value generics.
+        ValueSchema::Map(map_schema) => {
+            let entry_builders = vec![
+                make_builder(&map_schema.0, capacity)?,
+                make_builder(&map_schema.1, capacity)?,
+            ];
+            let entry_fields = vec![
+                Field::new("key", map_schema.0.to_arrow_datatype()?, false),
+                Field::new("value", map_schema.1.to_arrow_datatype()?, true),
+            ];
+            Box::new(ListBuilder::with_capacity(
+                StructBuilder::new(entry_fields, entry_builders),
+                capacity,
+            ))
+        }
+        ValueSchema::Group(group_schema) => {
+            let field_builders = group_schema
+                .0
+                .iter()
+                .map(|field_schema| make_builder(field_schema, capacity))
+                .collect::<Result<Vec<_>>>()?;
+            Box::new(StructBuilder::new(
+                group_schema.arrow_fields()?.to_vec(),
+                field_builders,
+            ))
+        }
+        ValueSchema::Option(option_schema) => make_builder(&option_schema.0, capacity)?,
+    })
+}
+
+fn append_value(
+    builder: &mut dyn ArrayBuilder, schema: &ValueSchema, value: &Value,
+) -> Result<()> {
+    if let Value::Option(None) = value {
+        return append_null(builder, schema);
+    }
+    let unwrapped;
+    let value = if let Value::Option(Some(inner)) = value {
+        unwrapped = Value::from(inner.clone());
+        &unwrapped
+    } else {
+        value
+    };
+    match schema {
+        ValueSchema::Bool(_) => {
+            downcast_mut::<BooleanBuilder>(builder).append_value(value.as_bool()?)
+        }
+        ValueSchema::U8(_) => {
+            downcast_mut::<UInt8Builder>(builder).append_value(value.as_u8()?)
+        }
+        ValueSchema::I8(_) => {
+            downcast_mut::<Int8Builder>(builder).append_value(value.as_i8()?)
+        }
+        ValueSchema::U16(_) => {
+            downcast_mut::<UInt16Builder>(builder).append_value(value.as_u16()?)
+        }
+        ValueSchema::I16(_) => {
+            downcast_mut::<Int16Builder>(builder).append_value(value.as_i16()?)
+        }
+        ValueSchema::U32(_) => {
+            downcast_mut::<UInt32Builder>(builder).append_value(value.as_u32()?)
+        }
+        ValueSchema::I32(_) => {
+            downcast_mut::<Int32Builder>(builder).append_value(value.as_i32()?)
+        }
+        ValueSchema::U64(_) => {
+            downcast_mut::<UInt64Builder>(builder).append_value(value.as_u64()?)
+        }
+        ValueSchema::I64(_) => {
+            downcast_mut::<Int64Builder>(builder).append_value(value.as_i64()?)
+        }
+        ValueSchema::F32(_) => {
+            downcast_mut::<Float32Builder>(builder).append_value(value.as_f32()?)
+        }
+        ValueSchema::F64(_) => {
+            downcast_mut::<Float64Builder>(builder).append_value(value.as_f64()?)
+        }
+        ValueSchema::Decimal(_) => {
+            // Parquet decimals are a big-endian two's-complement integer of varying
+            // byte width; widen to `i128` (sign-extending from the leading bit) to
+            // match what `Decimal128Builder` stores internally.
+            let bytes = value.as_decimal()?.as_bytes();
+            let sign = if bytes[0] & 0x80 != 0 { 0xffu8 } else { 0u8 };
+            let mut buf = [sign; 16];
+            buf[16 - bytes.len()..].copy_from_slice(bytes);
+            downcast_mut::<Decimal128Builder>(builder).append_value(i128::from_be_bytes(buf))
+        }
+        ValueSchema::Interval(_) => {
+            let interval = value.as_interval()?;
+            downcast_mut::<IntervalMonthDayNanoBuilder>(builder).append_value(
+                IntervalMonthDayNanoType::make_value(
+                    interval.months as i32,
+                    interval.days as i32,
+                    i64::from(interval.millis) * 1_000_000,
+                ),
+            )
+        }
+        ValueSchema::ByteArray(_) | ValueSchema::Bson(_) => {
+            downcast_mut::<BinaryBuilder>(builder).append_value(value.as_byte_array()?)
+        }
+        ValueSchema::String(_) => {
+            downcast_mut::<StringBuilder>(builder).append_value(value.as_string()?)
+        }
+        ValueSchema::Json(_) => downcast_mut::<StringBuilder>(builder)
+            .append_value(value.as_json()?.to_string()),
+        ValueSchema::Enum(_) => downcast_mut::<StringBuilder>(builder)
+            .append_value(value.as_enum()?.to_string()),
+        ValueSchema::IpAddr(_) => downcast_mut::<StringBuilder>(builder)
+            .append_value(value.as_ip_addr()?.to_string()),
+        ValueSchema::Url(_) => downcast_mut::<StringBuilder>(builder)
+            .append_value(value.as_url()?.to_string()),
+        ValueSchema::Webpage(_) => downcast_mut::<StringBuilder>(builder)
+            .append_value(value.as_webpage()?.to_string()),
+        ValueSchema::Timezone(_) => downcast_mut::<StringBuilder>(builder)
+            .append_value(value.as_timezone()?.to_string()),
+        ValueSchema::List(list_schema) => {
+            let list = value.as_list()?;
+            let builder = downcast_mut::<ListBuilder<Box<dyn ArrayBuilder>>>(builder);
+            for element in list.iter() {
+                append_value(builder.values().as_mut(), &list_schema.0, element)?;
+            }
+            builder.append(true);
+        }
+        ValueSchema::Map(map_schema) => {
+            let map = value.as_map()?;
+            let builder = downcast_mut::<ListBuilder<StructBuilder>>(builder);
+            for (key, val) in map.0.iter() {
+                let entries = builder.values();
+                append_value(entries.field_builder_dyn(0).unwrap(), &map_schema.0, key)?;
+                append_value(entries.field_builder_dyn(1).unwrap(), &map_schema.1, val)?;
+                entries.append(true);
+            }
+            builder.append(true);
+        }
+        ValueSchema::Group(group_schema) => {
+            let group = value.as_group()?;
+            let builder = downcast_mut::<StructBuilder>(builder);
+            for (i, field_schema) in group_schema.0.iter().enumerate() {
+                append_value(builder.field_builder_dyn(i).unwrap(), field_schema, &group.0[i])?;
+            }
+            builder.append(true);
+        }
+        ValueSchema::Option(option_schema) => {
+            append_value(builder, &option_schema.0, value)?;
+        }
+        _ => {
+            return Err(ParquetError::General(format!(
+                "Arrow bridge: {:?} isn't yet supported for appending",
+                schema
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn append_null(builder: &mut dyn ArrayBuilder, schema: &ValueSchema) -> Result<()> {
+    match schema {
+        ValueSchema::Bool(_) => downcast_mut::<BooleanBuilder>(builder).append_null(),
+        ValueSchema::U8(_) => downcast_mut::<UInt8Builder>(builder).append_null(),
+        ValueSchema::I8(_) => downcast_mut::<Int8Builder>(builder).append_null(),
+        ValueSchema::U16(_) => downcast_mut::<UInt16Builder>(builder).append_null(),
+        ValueSchema::I16(_) => downcast_mut::<Int16Builder>(builder).append_null(),
+        ValueSchema::U32(_) => downcast_mut::<UInt32Builder>(builder).append_null(),
+        ValueSchema::I32(_) => downcast_mut::<Int32Builder>(builder).append_null(),
+        ValueSchema::U64(_) => downcast_mut::<UInt64Builder>(builder).append_null(),
+        ValueSchema::I64(_) => downcast_mut::<Int64Builder>(builder).append_null(),
+        ValueSchema::F32(_) => downcast_mut::<Float32Builder>(builder).append_null(),
+        ValueSchema::F64(_) => downcast_mut::<Float64Builder>(builder).append_null(),
+        ValueSchema::Decimal(_) => downcast_mut::<Decimal128Builder>(builder).append_null(),
+        ValueSchema::Interval(_) => {
+            downcast_mut::<IntervalMonthDayNanoBuilder>(builder).append_null()
+        }
+        ValueSchema::ByteArray(_) | ValueSchema::Bson(_) => {
+            downcast_mut::<BinaryBuilder>(builder).append_null()
+        }
+        ValueSchema::String(_)
+        | ValueSchema::Json(_)
+        | ValueSchema::Enum(_)
+        | ValueSchema::IpAddr(_)
+        | ValueSchema::Url(_)
+        | ValueSchema::Webpage(_)
+        | ValueSchema::Timezone(_) => {
+            downcast_mut::<StringBuilder>(builder).append_null()
+        }
+        ValueSchema::List(_) => {
+            downcast_mut::<ListBuilder<Box<dyn ArrayBuilder>>>(builder).append(false)
+        }
+        ValueSchema::Map(_) => {
+            downcast_mut::<ListBuilder<StructBuilder>>(builder).append(false)
+        }
+        ValueSchema::Group(_) => downcast_mut::<StructBuilder>(builder).append(false),
+        ValueSchema::Option(option_schema) => append_null(builder, &option_schema.0)?,
+        _ => {
+            return Err(ParquetError::General(format!(
+                "Arrow bridge: {:?} isn't yet supported for appending",
+                schema
+            )));
+        }
+    }
+    Ok(())
+}
+
+fn downcast_mut<T: ArrayBuilder>(builder: &mut dyn ArrayBuilder) -> &mut T {
+    builder
+        .as_any_mut()
+        .downcast_mut::<T>()
+        .expect("Arrow bridge: builder/schema mismatch")
+}
@@ -15,12 +15,19 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Utility structs and methods to help with displaying schemas
+//! Utility structs and methods to help with displaying schemas, and parsing them back.
 
-use std::fmt::{self, Display, Write};
+use std::{
+    fmt::{self, Display, Write},
+    str::FromStr,
+};
 
 use super::Schema;
-use crate::basic::{LogicalType, Repetition};
+use crate::{
+    basic::{LogicalType, Repetition, Type as PhysicalType},
+    errors::{ParquetError, Result},
+    schema::types::Type,
+};
 
 /// Implement [`Display`] given a closure that accepts a [`fmt::Formatter`] and returns a
 /// [`fmt::Result`].
@@ -163,3 +170,402 @@ impl<'a, 'b: 'a> DisplaySchemaGroup<'a, 'b> {
         self.result
     }
 }
+
+/// Parses the canonical `message <name> { <field>* }` textual schema form – the same form
+/// [`DisplaySchemaGroup`]/[`DisplayFmt`] render a schema to – back into a [`Type`], so a
+/// disassemble→edit→reassemble round trip is possible.
+///
+/// This is a small recursive-descent parser: it tokenizes on whitespace and the
+/// punctuation `{ } ( ) ; = ,`, skipping `// ...` line comments, then walks a field list
+/// where each field is `<repetition> <type> <name> ["(" <logical-type> ")"] ["=" <field-id>] ";"`,
+/// recursing into nested `group`s (whose trailing `;` after the closing `}` is optional). `parse_message_type(schema.to_string()) == schema` for
+/// any schema `DisplaySchemaGroup` can render, including the `List`, `ListCompat` and
+/// `Repeated` shapes [`parse_list`](super::types::parse_list) produces.
+pub fn parse_message_type(s: &str) -> Result<Type> {
+    let tokens = tokenize(s)?;
+    let mut parser = Parser { tokens: &tokens, pos: 0 };
+    let ty = parser.parse_message()?;
+    if parser.pos != parser.tokens.len() {
+        return Err(ParquetError::General(format!(
+            "Unexpected trailing token {:?} after message",
+            parser.tokens[parser.pos]
+        )));
+    }
+    Ok(ty)
+}
+
+impl FromStr for Type {
+    type Err = ParquetError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_message_type(s)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Punct(char),
+}
+
+const PUNCTUATION: &str = "{}();=,";
+
+fn tokenize(s: &str) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some(&(i, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '/' && s[i..].starts_with("//") {
+            while let Some(&(_, c)) = chars.peek() {
+                if c == '\n' {
+                    break;
+                }
+                chars.next();
+            }
+        } else if PUNCTUATION.contains(c) {
+            tokens.push(Token::Punct(c));
+            chars.next();
+        } else {
+            let start = i;
+            let mut end = i + c.len_utf8();
+            chars.next();
+            while let Some(&(j, c)) = chars.peek() {
+                if c.is_whitespace() || PUNCTUATION.contains(c) {
+                    break;
+                }
+                end = j + c.len_utf8();
+                chars.next();
+            }
+            tokens.push(Token::Ident(s[start..end].to_owned()));
+        }
+    }
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Result<&'a Token> {
+        let token = self.tokens.get(self.pos).ok_or_else(|| {
+            ParquetError::General(String::from("Unexpected end of input parsing schema"))
+        })?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn expect_ident(&mut self) -> Result<&'a str> {
+        match self.bump()? {
+            Token::Ident(ident) => Ok(ident),
+            token => Err(ParquetError::General(format!(
+                "Expected an identifier, found {:?}",
+                token
+            ))),
+        }
+    }
+
+    fn expect_punct(&mut self, expected: char) -> Result<()> {
+        match self.bump()? {
+            Token::Punct(c) if *c == expected => Ok(()),
+            token => Err(ParquetError::General(format!(
+                "Expected {:?}, found {:?}",
+                expected, token
+            ))),
+        }
+    }
+
+    fn eat_punct(&mut self, expected: char) -> bool {
+        match self.peek() {
+            Some(Token::Punct(c)) if *c == expected => {
+                self.pos += 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_message(&mut self) -> Result<Type> {
+        match self.expect_ident()? {
+            "message" => (),
+            other => {
+                return Err(ParquetError::General(format!(
+                    "Expected \"message\", found {:?}",
+                    other
+                )))
+            }
+        }
+        let name = self.expect_ident()?.to_owned();
+        let mut fields = self.parse_field_list()?;
+        Type::group_type_builder(&name)
+            .with_fields(&mut fields)
+            .build()
+    }
+
+    fn parse_field_list(&mut self) -> Result<Vec<std::sync::Arc<Type>>> {
+        self.expect_punct('{')?;
+        let mut fields = Vec::new();
+        while !self.eat_punct('}') {
+            fields.push(std::sync::Arc::new(self.parse_field()?));
+        }
+        Ok(fields)
+    }
+
+    fn parse_field(&mut self) -> Result<Type> {
+        let repetition = match self.expect_ident()? {
+            "required" => Repetition::Required,
+            "optional" => Repetition::Optional,
+            "repeated" => Repetition::Repeated,
+            other => {
+                return Err(ParquetError::General(format!(
+                    "Expected a repetition (required/optional/repeated), found {:?}",
+                    other
+                )))
+            }
+        };
+        let type_name = self.expect_ident()?.to_owned();
+        let ty = if type_name == "group" {
+            let name = self.expect_ident()?.to_owned();
+            let logical_type = self.parse_optional_logical_type()?;
+            let mut fields = self.parse_field_list()?;
+            let id = self.parse_optional_field_id()?;
+            // `DisplaySchemaGroup::finish` closes a group with a bare `}`, so the `;` after
+            // a group is optional.
+            let _ = self.eat_punct(';');
+            let mut builder = Type::group_type_builder(&name)
+                .with_repetition(repetition)
+                .with_logical_type(
+                    logical_type.map_or(LogicalType::None, |(logical_type, _, _)| logical_type),
+                )
+                .with_fields(&mut fields);
+            if let Some(id) = id {
+                builder = builder.with_id(id);
+            }
+            builder.build()?
+        } else {
+            let (physical_type, length) = self.parse_physical_type(&type_name)?;
+            let name = self.expect_ident()?.to_owned();
+            let logical_type = self.parse_optional_logical_type()?;
+            let id = self.parse_optional_field_id()?;
+            self.expect_punct(';')?;
+            let mut builder = Type::primitive_type_builder(&name, physical_type)
+                .with_repetition(repetition)
+                .with_logical_type(
+                    logical_type.map_or(LogicalType::None, |(logical_type, _, _)| logical_type),
+                );
+            if let Some(length) = length {
+                builder = builder.with_length(length);
+            }
+            if let Some((LogicalType::Decimal, precision, scale)) = logical_type {
+                builder = builder.with_precision(precision).with_scale(scale);
+            }
+            if let Some(id) = id {
+                builder = builder.with_id(id);
+            }
+            builder.build()?
+        };
+        Ok(ty)
+    }
+
+    fn parse_physical_type(
+        &mut self, name: &str,
+    ) -> Result<(PhysicalType, Option<i32>)> {
+        Ok(match name {
+            "boolean" => (PhysicalType::Boolean, None),
+            "int32" => (PhysicalType::Int32, None),
+            "int64" => (PhysicalType::Int64, None),
+            "int96" => (PhysicalType::Int96, None),
+            "float" => (PhysicalType::Float, None),
+            "double" => (PhysicalType::Double, None),
+            "binary" => (PhysicalType::ByteArray, None),
+            "fixed_len_byte_array" => {
+                self.expect_punct('(')?;
+                let length = self.expect_integer()?;
+                self.expect_punct(')')?;
+                (PhysicalType::FixedLenByteArray, Some(length))
+            }
+            other => {
+                return Err(ParquetError::General(format!(
+                    "Unknown physical type {:?}",
+                    other
+                )))
+            }
+        })
+    }
+
+    fn parse_optional_logical_type(&mut self) -> Result<Option<(LogicalType, i32, i32)>> {
+        if !self.eat_punct('(') {
+            return Ok(None);
+        }
+        let name = self.expect_ident()?;
+        let logical_type = match name {
+            "UTF8" => LogicalType::Utf8,
+            "MAP" => LogicalType::Map,
+            "MAP_KEY_VALUE" => LogicalType::MapKeyValue,
+            "LIST" => LogicalType::List,
+            "ENUM" => LogicalType::Enum,
+            "DATE" => LogicalType::Date,
+            "TIME_MILLIS" => LogicalType::TimeMillis,
+            "TIME_MICROS" => LogicalType::TimeMicros,
+            "TIMESTAMP_MILLIS" => LogicalType::TimestampMillis,
+            "TIMESTAMP_MICROS" => LogicalType::TimestampMicros,
+            "UINT_8" => LogicalType::Uint8,
+            "UINT_16" => LogicalType::Uint16,
+            "UINT_32" => LogicalType::Uint32,
+            "UINT_64" => LogicalType::Uint64,
+            "INT_8" => LogicalType::Int8,
+            "INT_16" => LogicalType::Int16,
+            "INT_32" => LogicalType::Int32,
+            "INT_64" => LogicalType::Int64,
+            "JSON" => LogicalType::Json,
+            "BSON" => LogicalType::Bson,
+            "INTERVAL" => LogicalType::Interval,
+            "DECIMAL" => LogicalType::Decimal,
+            other => {
+                return Err(ParquetError::General(format!(
+                    "Unknown logical type {:?}",
+                    other
+                )))
+            }
+        };
+        let (precision, scale) = if logical_type == LogicalType::Decimal && self.eat_punct('(')
+        {
+            let precision = self.expect_integer()?;
+            self.expect_punct(',')?;
+            let scale = self.expect_integer()?;
+            self.expect_punct(')')?;
+            (precision, scale)
+        } else {
+            (0, 0)
+        };
+        self.expect_punct(')')?;
+        Ok(Some((logical_type, precision, scale)))
+    }
+
+    fn parse_optional_field_id(&mut self) -> Result<Option<i32>> {
+        if !self.eat_punct('=') {
+            return Ok(None);
+        }
+        Ok(Some(self.expect_integer()?))
+    }
+
+    fn expect_integer(&mut self) -> Result<i32> {
+        let ident = self.expect_ident()?;
+        ident.parse::<i32>().map_err(|_| {
+            ParquetError::General(format!("Expected an integer, found {:?}", ident))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    fn primitive(name: &str, physical_type: PhysicalType, repetition: Repetition) -> Type {
+        Type::primitive_type_builder(name, physical_type)
+            .with_repetition(repetition)
+            .build()
+            .unwrap()
+    }
+
+    fn round_trips(schema: &Type) {
+        let rendered = schema.to_string();
+        let parsed = parse_message_type(&rendered)
+            .unwrap_or_else(|err| panic!("failed to parse {:?}: {}", rendered, err));
+        assert_eq!(&parsed, schema, "round trip through {:?}", rendered);
+    }
+
+    // Modern three-level encoding: LIST-annotated group -> repeated "list" group ->
+    // "element" field.
+    #[test]
+    fn round_trips_modern_three_level_list() {
+        let element = primitive("element", PhysicalType::Int32, Repetition::Required);
+        let list = Type::group_type_builder("list")
+            .with_repetition(Repetition::Repeated)
+            .with_fields(&mut vec![Arc::new(element)])
+            .build()
+            .unwrap();
+        let schema = Type::group_type_builder("schema")
+            .with_fields(&mut vec![Arc::new(
+                Type::group_type_builder("my_list")
+                    .with_repetition(Repetition::Optional)
+                    .with_logical_type(LogicalType::List)
+                    .with_fields(&mut vec![Arc::new(list)])
+                    .build()
+                    .unwrap(),
+            )])
+            .build()
+            .unwrap();
+        round_trips(&schema);
+    }
+
+    // ListCompat two-level encoding: LIST-annotated group -> repeated "array" field,
+    // with no intermediate group.
+    #[test]
+    fn round_trips_list_compat_two_level() {
+        let array = primitive("array", PhysicalType::Int32, Repetition::Repeated);
+        let schema = Type::group_type_builder("schema")
+            .with_fields(&mut vec![Arc::new(
+                Type::group_type_builder("my_list")
+                    .with_repetition(Repetition::Optional)
+                    .with_logical_type(LogicalType::List)
+                    .with_fields(&mut vec![Arc::new(array)])
+                    .build()
+                    .unwrap(),
+            )])
+            .build()
+            .unwrap();
+        round_trips(&schema);
+    }
+
+    // Bare repeated field with no LIST annotation at all.
+    #[test]
+    fn round_trips_unannotated_repeated_field() {
+        let field = primitive("values", PhysicalType::Int32, Repetition::Repeated);
+        let schema = Type::group_type_builder("schema")
+            .with_fields(&mut vec![Arc::new(field)])
+            .build()
+            .unwrap();
+        round_trips(&schema);
+    }
+
+    // A non-decimal logical type must not pick up the `with_precision(0)/with_scale(0)`
+    // that only makes sense for `DECIMAL`.
+    #[test]
+    fn round_trips_non_decimal_logical_type() {
+        let field = Type::primitive_type_builder("name", PhysicalType::ByteArray)
+            .with_repetition(Repetition::Required)
+            .with_logical_type(LogicalType::Utf8)
+            .build()
+            .unwrap();
+        let schema = Type::group_type_builder("schema")
+            .with_fields(&mut vec![Arc::new(field)])
+            .build()
+            .unwrap();
+        round_trips(&schema);
+    }
+
+    #[test]
+    fn round_trips_decimal_precision_and_scale() {
+        let field = Type::primitive_type_builder("amount", PhysicalType::Int32)
+            .with_repetition(Repetition::Required)
+            .with_logical_type(LogicalType::Decimal)
+            .with_precision(9)
+            .with_scale(2)
+            .build()
+            .unwrap();
+        let schema = Type::group_type_builder("schema")
+            .with_fields(&mut vec![Arc::new(field)])
+            .build()
+            .unwrap();
+        round_trips(&schema);
+    }
+}
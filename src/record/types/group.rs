@@ -19,8 +19,14 @@
 
 use fxhash::FxBuildHasher;
 use linked_hash_map::LinkedHashMap;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{Error as DeError, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     fmt::{self, Debug},
     ops::{Index, IndexMut},
     str,
@@ -33,7 +39,7 @@ use crate::{
     errors::{ParquetError, Result},
     record::{
         reader::GroupReader,
-        schemas::{GroupSchema, ValueSchema},
+        schemas::{GroupSchema, ListSchema, OptionSchema, ValueSchema},
         types::Value,
         Record,
     },
@@ -50,6 +56,27 @@ pub struct Group(
     pub(crate) Vec<Value>,
     pub(crate) Arc<LinkedHashMap<String, usize, FxBuildHasher>>,
 );
+impl Eq for Group {}
+impl PartialOrd for Group {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+/// Orders `Group`s field-by-field in schema order, i.e. by their underlying `Vec<Value>`
+/// first, falling back to comparing field names if the values are equal. The fallback
+/// keeps this consistent with the derived `PartialEq` (which also compares field names):
+/// without it, two groups with equal values but differently-named fields would compare
+/// `Equal` under `Ord` while being unequal under `Eq`.
+impl Ord for Group {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0).then_with(|| {
+            self.1
+                .iter()
+                .map(|(name, _index)| name)
+                .cmp(other.1.iter().map(|(name, _index)| name))
+        })
+    }
+}
 /// [`Row`] is identical to [`Group`] in every way; this alias exists as arguably reading
 /// rows into a type called `Row` is more idiomatic than into a type called `Group`.
 pub type Row = Group;
@@ -118,7 +145,77 @@ impl Record for Group {
     }
 }
 
+impl GroupSchema {
+    /// Restricts this schema to the fields needed to reach the leaf columns named in
+    /// `columns`, recursing into nested `Group`s (through any wrapping `Option`/`List`)
+    /// so only fields on the path to one of those leaves survive. Field order of whatever
+    /// survives is unchanged.
+    pub fn project(&self, columns: &HashSet<ColumnPath>) -> Self {
+        let mut fields = Vec::new();
+        let mut map = LinkedHashMap::with_capacity_and_hasher(
+            self.1.len(),
+            Default::default(),
+        );
+        for (name, &index) in self.1.iter() {
+            let suffixes: HashSet<ColumnPath> = columns
+                .iter()
+                .filter(|column| {
+                    column.parts().first().map(String::as_str) == Some(name.as_str())
+                })
+                .map(|column| ColumnPath::new(column.parts()[1..].to_vec()))
+                .collect();
+            if suffixes.is_empty() {
+                continue;
+            }
+            let i = fields.len();
+            fields.push(self.0[index].project(&suffixes));
+            let _ = map.insert(name.clone(), i);
+        }
+        GroupSchema(fields, map)
+    }
+}
+
+impl ValueSchema {
+    /// Restricts this schema to the leaf columns named in `columns` (paths relative to
+    /// this schema), recursing through `Group`/`Option`/`List`. A `columns` entry whose
+    /// path is fully consumed (i.e. is empty by the time it reaches this schema) selects
+    /// this whole subtree rather than pruning further into it.
+    fn project(&self, columns: &HashSet<ColumnPath>) -> Self {
+        if columns.iter().any(|column| column.parts().is_empty()) {
+            return self.clone();
+        }
+        match self {
+            ValueSchema::Group(group_schema) => {
+                ValueSchema::Group(group_schema.project(columns))
+            }
+            ValueSchema::Option(option_schema) => {
+                ValueSchema::Option(Box::new(OptionSchema(option_schema.0.project(columns))))
+            }
+            ValueSchema::List(list_schema) => ValueSchema::List(Box::new(ListSchema(
+                list_schema.0.project(columns),
+                list_schema.1.clone(),
+            ))),
+            _ => self.clone(),
+        }
+    }
+}
+
 impl Group {
+    /// Like [`Record::reader`], but only constructs a `Value::reader`/registers a
+    /// `ColumnReader` for fields on the path to one of the leaf columns named in
+    /// `columns` — pruned columns cost no I/O. The resulting `Group`s contain just the
+    /// projected fields, in schema order.
+    pub fn reader_projected(
+        schema: &GroupSchema, columns: &HashSet<ColumnPath>, path: &mut Vec<String>,
+        def_level: i16, rep_level: i16, paths: &mut HashMap<ColumnPath, ColumnReader>,
+        batch_size: usize,
+    ) -> GroupReader {
+        let projected = schema.project(columns);
+        <Group as Record>::reader(
+            &projected, path, def_level, rep_level, paths, batch_size,
+        )
+    }
+
     #[doc(hidden)]
     pub fn new(
         fields: Vec<Value>,
@@ -206,3 +303,320 @@ impl From<Group> for LinkedHashMap<String, Value, FxBuildHasher> {
             .collect()
     }
 }
+
+#[cfg(feature = "serde")]
+impl Serialize for Group {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (name, value) in self.1.iter().map(|(name, _index)| name).zip(self.0.iter()) {
+            map.serialize_entry(name, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Group {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct GroupVisitor;
+
+        impl<'de> Visitor<'de> for GroupVisitor {
+            type Value = Group;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of field name to Parquet Value")
+            }
+
+            fn visit_map<A>(
+                self, mut access: A,
+            ) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut fields = Vec::with_capacity(access.size_hint().unwrap_or(0));
+                let mut names = LinkedHashMap::with_capacity_and_hasher(
+                    access.size_hint().unwrap_or(0),
+                    Default::default(),
+                );
+                while let Some((name, value)) = access.next_entry::<String, Value>()? {
+                    if names.insert(name, fields.len()).is_some() {
+                        return Err(A::Error::custom("duplicate field in Group"));
+                    }
+                    fields.push(value);
+                }
+                Ok(Group(fields, Arc::new(names)))
+            }
+        }
+
+        deserializer.deserialize_map(GroupVisitor)
+    }
+}
+
+/// A run-length-encoded set of rows to keep when reading a row group: alternating
+/// select/skip ranges. Appending a range of the same kind as the last one extends it, and
+/// zero-length appends are ignored, so a selection built from [`Default`] starts with
+/// whichever kind of range is appended first.
+///
+/// This only models *which* rows a reader should materialize. Whole row groups are pruned
+/// by [`evaluate_row_group`]; nothing in this module builds a `RowSelection` from a
+/// predicate, and `Value::reader`/`ValueReader` take no `RowSelection`, so readers still
+/// decode every row of a row group they read.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RowSelection {
+    ranges: Vec<RowSelectionRange>,
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct RowSelectionRange {
+    len: usize,
+    select: bool,
+}
+impl RowSelection {
+    /// A selection over `len` rows that selects all of them.
+    pub fn all(len: usize) -> Self {
+        Self { ranges: vec![RowSelectionRange { len, select: true }] }
+    }
+    /// Appends a range of `len` rows to keep.
+    pub fn select(&mut self, len: usize) -> &mut Self {
+        self.push(len, true)
+    }
+    /// Appends a range of `len` rows to skip.
+    pub fn skip(&mut self, len: usize) -> &mut Self {
+        self.push(len, false)
+    }
+    fn push(&mut self, len: usize, select: bool) -> &mut Self {
+        if len == 0 {
+            return self;
+        }
+        match self.ranges.last_mut() {
+            Some(last) if last.select == select => last.len += len,
+            _ => self.ranges.push(RowSelectionRange { len, select }),
+        }
+        self
+    }
+    /// The total number of rows this selection spans, selected and skipped alike.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|range| range.len).sum()
+    }
+    /// Whether this selection spans no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// The number of rows this selection selects.
+    pub fn selected_len(&self) -> usize {
+        self.ranges
+            .iter()
+            .filter(|range| range.select)
+            .map(|range| range.len)
+            .sum()
+    }
+    /// Iterates over the `(len, select)` ranges making up this selection, in order.
+    pub fn ranges(&self) -> impl Iterator<Item = (usize, bool)> + '_ {
+        self.ranges.iter().map(|range| (range.len, range.select))
+    }
+}
+
+/// The outcome of evaluating a predicate against a row group's statistics: prune it
+/// entirely, read every row, or read only the rows covered by a [`RowSelection`].
+///
+/// [`evaluate_row_group`] can only ever return [`PruneEntirely`](Self::PruneEntirely) or
+/// [`ReadAll`](Self::ReadAll): producing a [`Select`](Self::Select) needs page-level
+/// statistics (to know *which* rows within the row group the predicate rules out, not
+/// just whether it rules out all of them), and the page index types this would read
+/// aren't part of this checkout. Threading a `RowSelection` through
+/// `Value::reader`/`ValueReader` so a reader advances def/rep levels past skipped rows
+/// instead of decoding them is deferred for the same reason.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RowGroupFilterResult {
+    /// Every row in the row group fails the predicate; skip it, and the file bytes backing
+    /// it, entirely.
+    PruneEntirely,
+    /// The predicate doesn't rule out any row; read the row group unfiltered.
+    ReadAll,
+    /// Only the rows this selection selects satisfy the predicate.
+    Select(RowSelection),
+}
+
+/// The minimum and maximum value of a column within some unit of the file (a row group or
+/// a page), as recorded in its statistics. Either bound may be absent: writers aren't
+/// required to track statistics, and some logical types opt out of min/max tracking
+/// entirely (e.g. values that don't have a total order).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ColumnStatistics {
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+}
+
+/// A predicate over a single column's statistics, used to decide whether a row group (or,
+/// with page-level statistics, a page) can be pruned without being read.
+///
+/// This only expresses the comparisons that row-group-level min/max statistics can
+/// actually answer; it isn't a general expression language.
+#[derive(Clone, Debug, PartialEq)]
+pub enum StatisticsPredicate {
+    /// The column is equal to this value somewhere in the range.
+    Eq(Value),
+    /// The column is less than this value somewhere in the range.
+    Lt(Value),
+    /// The column is less than or equal to this value somewhere in the range.
+    LtEq(Value),
+    /// The column is greater than this value somewhere in the range.
+    Gt(Value),
+    /// The column is greater than or equal to this value somewhere in the range.
+    GtEq(Value),
+    /// Both sub-predicates may match.
+    And(Box<StatisticsPredicate>, Box<StatisticsPredicate>),
+    /// Either sub-predicate may match.
+    Or(Box<StatisticsPredicate>, Box<StatisticsPredicate>),
+}
+
+impl StatisticsPredicate {
+    /// Returns `false` only when `stats` prove that no row in the range this predicate is
+    /// being evaluated over could satisfy it; otherwise returns `true`, including when a
+    /// bound is unknown. This is the conservative direction pruning needs: a spurious
+    /// `true` just costs an unnecessary read, whereas a spurious `false` would silently
+    /// drop rows that should have been read.
+    pub fn may_match(&self, stats: &ColumnStatistics) -> bool {
+        match self {
+            StatisticsPredicate::Eq(value) => {
+                stats.min.as_ref().map_or(true, |min| min <= value)
+                    && stats.max.as_ref().map_or(true, |max| max >= value)
+            }
+            StatisticsPredicate::Lt(value) => {
+                stats.min.as_ref().map_or(true, |min| min < value)
+            }
+            StatisticsPredicate::LtEq(value) => {
+                stats.min.as_ref().map_or(true, |min| min <= value)
+            }
+            StatisticsPredicate::Gt(value) => {
+                stats.max.as_ref().map_or(true, |max| max > value)
+            }
+            StatisticsPredicate::GtEq(value) => {
+                stats.max.as_ref().map_or(true, |max| max >= value)
+            }
+            StatisticsPredicate::And(a, b) => a.may_match(stats) && b.may_match(stats),
+            StatisticsPredicate::Or(a, b) => a.may_match(stats) || b.may_match(stats),
+        }
+    }
+}
+
+/// Evaluates `predicate` against `column`'s row-group statistics, deciding whether the
+/// row group can be pruned entirely or must be read in full. A column missing from
+/// `statistics` (no statistics were written for it) is treated as matching, since absence
+/// doesn't rule anything out.
+///
+/// This is row-*group* pruning only: it never returns
+/// [`Select`](RowGroupFilterResult::Select), since doing so would need page-level
+/// statistics this checkout doesn't have (see [`RowGroupFilterResult`]'s doc). Row-level
+/// pruning within a row group that survives this check isn't performed.
+pub fn evaluate_row_group(
+    predicate: &StatisticsPredicate, column: &ColumnPath,
+    statistics: &HashMap<ColumnPath, ColumnStatistics>,
+) -> RowGroupFilterResult {
+    match statistics.get(column) {
+        Some(stats) if !predicate.may_match(stats) => RowGroupFilterResult::PruneEntirely,
+        _ => RowGroupFilterResult::ReadAll,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(min: i32, max: i32) -> ColumnStatistics {
+        ColumnStatistics { min: Some(Value::I32(min)), max: Some(Value::I32(max)) }
+    }
+
+    #[test]
+    fn row_selection_builds_up_run_length_ranges() {
+        let mut selection = RowSelection::all(0);
+        let _ = selection.select(3).skip(0).select(2).skip(4).select(1);
+        assert_eq!(selection.len(), 10);
+        assert_eq!(selection.selected_len(), 6);
+        assert_eq!(
+            selection.ranges().collect::<Vec<_>>(),
+            vec![(5, true), (4, false), (1, true)]
+        );
+    }
+
+    #[test]
+    fn row_selection_all_selects_everything() {
+        let selection = RowSelection::all(5);
+        assert_eq!(selection.len(), 5);
+        assert_eq!(selection.selected_len(), 5);
+        assert_eq!(selection.ranges().collect::<Vec<_>>(), vec![(5, true)]);
+    }
+
+    #[test]
+    fn predicate_eq_may_match_within_bounds() {
+        let predicate = StatisticsPredicate::Eq(Value::I32(5));
+        assert!(predicate.may_match(&stats(0, 10)));
+        assert!(!predicate.may_match(&stats(6, 10)));
+        assert!(!predicate.may_match(&stats(0, 4)));
+    }
+
+    #[test]
+    fn predicate_comparisons_respect_bounds() {
+        assert!(StatisticsPredicate::Lt(Value::I32(5)).may_match(&stats(0, 10)));
+        assert!(!StatisticsPredicate::Lt(Value::I32(5)).may_match(&stats(5, 10)));
+        assert!(StatisticsPredicate::GtEq(Value::I32(5)).may_match(&stats(0, 10)));
+        assert!(!StatisticsPredicate::Gt(Value::I32(10)).may_match(&stats(0, 10)));
+    }
+
+    #[test]
+    fn predicate_missing_bound_cannot_prune() {
+        let unbounded = ColumnStatistics { min: None, max: None };
+        assert!(StatisticsPredicate::Eq(Value::I32(5)).may_match(&unbounded));
+        assert!(StatisticsPredicate::Gt(Value::I32(1000)).may_match(&unbounded));
+    }
+
+    #[test]
+    fn predicate_and_or_combine_sub_predicates() {
+        let stats = stats(0, 10);
+        let and = StatisticsPredicate::And(
+            Box::new(StatisticsPredicate::GtEq(Value::I32(5))),
+            Box::new(StatisticsPredicate::LtEq(Value::I32(3))),
+        );
+        assert!(!and.may_match(&stats));
+        let or = StatisticsPredicate::Or(
+            Box::new(StatisticsPredicate::GtEq(Value::I32(5))),
+            Box::new(StatisticsPredicate::LtEq(Value::I32(3))),
+        );
+        assert!(or.may_match(&stats));
+    }
+
+    #[test]
+    fn evaluate_row_group_prunes_when_stats_rule_out_predicate() {
+        let column = ColumnPath::from(vec![String::from("a")]);
+        let mut statistics = HashMap::new();
+        let _ = statistics.insert(column.clone(), stats(0, 10));
+
+        let result = evaluate_row_group(
+            &StatisticsPredicate::Gt(Value::I32(100)),
+            &column,
+            &statistics,
+        );
+        assert_eq!(result, RowGroupFilterResult::PruneEntirely);
+
+        let result = evaluate_row_group(
+            &StatisticsPredicate::Gt(Value::I32(5)),
+            &column,
+            &statistics,
+        );
+        assert_eq!(result, RowGroupFilterResult::ReadAll);
+    }
+
+    #[test]
+    fn evaluate_row_group_reads_all_when_statistics_missing() {
+        let column = ColumnPath::from(vec![String::from("missing")]);
+        let statistics = HashMap::new();
+        let result =
+            evaluate_row_group(&StatisticsPredicate::Gt(Value::I32(100)), &column, &statistics);
+        assert_eq!(result, RowGroupFilterResult::ReadAll);
+    }
+}
@@ -17,9 +17,16 @@
 
 //! Implement [`Record`] for [`List`].
 
+#[cfg(feature = "serde")]
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
 use std::{
     collections::HashMap,
     fmt::{self, Debug},
+    marker::PhantomData,
     ops::Index,
     slice::{self, SliceIndex},
     vec,
@@ -31,15 +38,20 @@ use crate::{
     errors::{ParquetError, Result},
     record::{
         reader::{MapReader, RepeatedReader},
-        schemas::{ListSchema, ListSchemaType},
+        schemas::{ListSchema, ListSchemaType, MapSchema},
         Reader, Record,
     },
     schema::types::{ColumnPath, Type},
 };
 
-/// Returns true if repeated type is an element type for the list.
-/// Used to determine legacy list types.
-/// This method is copied from Spark Parquet reader and is based on the reference:
+/// Parses a `LIST`-annotated group into a [`ListSchema`], recognizing both the modern
+/// three-level encoding (an intermediate repeated group, conventionally named `list`,
+/// wrapping a single `element` field) and the legacy two-level encodings written by older
+/// writers (Hive, Spark 1.x, Thrift/Impala): a repeated field directly under the
+/// `LIST`-annotated group that is itself the list element, whether because it isn't a
+/// group, is a group with more than one field, or is a single-field group conventionally
+/// named `array` or `{name}_tuple`. The distinguishing logic is copied from the Spark
+/// Parquet reader and is based on the reference:
 /// https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#backward-compatibility-rules
 pub(super) fn parse_list<T: Record>(schema: &Type) -> Result<ListSchema<T::Schema>> {
     if schema.is_group()
@@ -86,6 +98,43 @@ pub(super) fn parse_list<T: Record>(schema: &Type) -> Result<ListSchema<T::Schem
     )))
 }
 
+/// Parses a legacy `MAP_KEY_VALUE`-annotated group into a [`MapSchema`], as written by
+/// writers that predate the `MAP` logical type (older Hive, Thrift/Impala). Structurally
+/// this is the same shape the modern `MAP` encoding uses - a single repeated child group
+/// with exactly two fields, conventionally `key` and `value` - except the annotation sits
+/// directly on the outer group instead of on a `MAP`-annotated wrapper, and the repeated
+/// child isn't required to be named `key_value`. Call this as a fallback after
+/// [`map::parse_map`](super::map::parse_map) fails, per the reference:
+/// https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#backward-compatibility-rules
+pub(super) fn parse_map_key_value_legacy<K: Record, V: Record>(
+    schema: &Type,
+) -> Result<MapSchema<K::Schema, V::Schema>> {
+    if schema.is_group()
+        && schema.get_basic_info().logical_type() == LogicalType::MapKeyValue
+        && schema.get_fields().len() == 1
+    {
+        let key_value = schema.get_fields().into_iter().nth(0).unwrap();
+        if key_value.get_basic_info().repetition() == Repetition::Repeated
+            && key_value.is_group()
+            && key_value.get_fields().len() == 2
+        {
+            let mut fields = key_value.get_fields().into_iter();
+            let key = fields.next().unwrap();
+            let value = fields.next().unwrap();
+            return Ok(MapSchema(
+                K::parse(&*key, Some(key.get_basic_info().repetition()))?.1,
+                V::parse(&*value, Some(value.get_basic_info().repetition()))?.1,
+                None,
+                None,
+                None,
+            ));
+        }
+    }
+    Err(ParquetError::General(String::from(
+        "Couldn't parse legacy MAP_KEY_VALUE Map<K, V>",
+    )))
+}
+
 /// `List<T>` corresponds to the [List logical type](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#lists).
 #[derive(Clone, Hash, Eq)]
 pub struct List<T>(pub(in super::super) Vec<T>);
@@ -231,3 +280,176 @@ where
         f.debug_list().entries(self.iter()).finish()
     }
 }
+
+#[cfg(feature = "serde")]
+impl<T> Serialize for List<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for element in &self.0 {
+            seq.serialize_element(element)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for List<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for ListVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = List<T>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a sequence of Parquet values")
+            }
+
+            fn visit_seq<A>(
+                self, mut seq: A,
+            ) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut elements = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(element) = seq.next_element()? {
+                    elements.push(element);
+                }
+                Ok(List(elements))
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        basic::Type as PhysicalType, record::schemas::ValueSchema, record::types::Value,
+    };
+
+    fn group(name: &str, logical_type: LogicalType, fields: Vec<Type>) -> Type {
+        Type::group_type_builder(name)
+            .with_repetition(Repetition::Optional)
+            .with_logical_type(logical_type)
+            .with_fields(&mut fields.into_iter().map(Arc::new).collect())
+            .build()
+            .unwrap()
+    }
+
+    fn primitive(name: &str, physical_type: PhysicalType, repetition: Repetition) -> Type {
+        Type::primitive_type_builder(name, physical_type)
+            .with_repetition(repetition)
+            .build()
+            .unwrap()
+    }
+
+    // Modern three-level encoding: LIST-annotated group -> repeated "list" group ->
+    // "element" field.
+    #[test]
+    fn parse_list_modern_three_level() {
+        let element = primitive("element", PhysicalType::Int32, Repetition::Required);
+        let list = Type::group_type_builder("list")
+            .with_repetition(Repetition::Repeated)
+            .with_fields(&mut vec![Arc::new(element)])
+            .build()
+            .unwrap();
+        let schema = group("my_list", LogicalType::List, vec![list]);
+
+        let parsed = parse_list::<Value>(&schema).unwrap();
+        assert_eq!(parsed.1, ListSchemaType::List(None, None));
+    }
+
+    // Spark 1.x-style two-level encoding: LIST-annotated group -> repeated primitive
+    // named "array".
+    #[test]
+    fn parse_list_two_level_array_compat() {
+        let array = primitive("array", PhysicalType::Int32, Repetition::Repeated);
+        let schema = group("my_list", LogicalType::List, vec![array]);
+
+        let parsed = parse_list::<Value>(&schema).unwrap();
+        assert_eq!(
+            parsed.1,
+            ListSchemaType::ListCompat(String::from("array"))
+        );
+    }
+
+    // Older Thrift/Impala-style two-level encoding: LIST-annotated group -> repeated
+    // single-field group named "{name}_tuple".
+    #[test]
+    fn parse_list_two_level_tuple_compat() {
+        let inner = primitive("value", PhysicalType::Int32, Repetition::Required);
+        let tuple = Type::group_type_builder("my_list_tuple")
+            .with_repetition(Repetition::Repeated)
+            .with_fields(&mut vec![Arc::new(inner)])
+            .build()
+            .unwrap();
+        let schema = group("my_list", LogicalType::List, vec![tuple]);
+
+        let parsed = parse_list::<Value>(&schema).unwrap();
+        assert_eq!(
+            parsed.1,
+            ListSchemaType::ListCompat(String::from("my_list_tuple"))
+        );
+    }
+
+    // Unannotated repeated field, used when a LIST/MAP-annotated wrapper is absent: the
+    // repeated field itself is a required list of required elements.
+    #[test]
+    fn parse_list_bare_repeated_field() {
+        let schema = primitive("values", PhysicalType::Int32, Repetition::Repeated);
+
+        let (name, schema) = Value::parse(&schema, Some(Repetition::Repeated)).unwrap();
+        assert_eq!(name, "values");
+        match schema {
+            ValueSchema::List(list) => assert_eq!(list.1, ListSchemaType::Repeated),
+            other => panic!("expected ValueSchema::List, got {:?}", other),
+        }
+    }
+
+    // Legacy MAP_KEY_VALUE encoding, written by writers that predate the MAP logical
+    // type: MAP_KEY_VALUE-annotated group -> repeated group with "key"/"value" fields.
+    #[test]
+    fn parse_map_key_value_legacy_two_level() {
+        let key = Type::primitive_type_builder("key", PhysicalType::ByteArray)
+            .with_repetition(Repetition::Required)
+            .with_logical_type(LogicalType::Utf8)
+            .build()
+            .unwrap();
+        let value = primitive("value", PhysicalType::Int32, Repetition::Optional);
+        let key_value = Type::group_type_builder("map")
+            .with_repetition(Repetition::Repeated)
+            .with_fields(&mut vec![Arc::new(key), Arc::new(value)])
+            .build()
+            .unwrap();
+        let schema = group("my_map", LogicalType::MapKeyValue, vec![key_value]);
+
+        let parsed = parse_map_key_value_legacy::<Value, Value>(&schema).unwrap();
+        assert!(matches!(parsed.0, ValueSchema::String(_)));
+        assert!(matches!(parsed.1, ValueSchema::Option(_)));
+    }
+
+    #[test]
+    fn parse_map_key_value_legacy_rejects_non_map_key_value_group() {
+        let schema = group("not_a_map", LogicalType::None, vec![]);
+        assert!(parse_map_key_value_legacy::<Value, Value>(&schema).is_err());
+    }
+}
@@ -18,9 +18,17 @@
 //! Implement [`Record`] for [`Value`] – an enum representing any valid Parquet value.
 
 use linked_hash_map::LinkedHashMap;
+#[cfg(feature = "serde")]
+use serde::{
+    de::{EnumAccess, Error as DeError, MapAccess, Unexpected, VariantAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_json::{Map as JsonMap, Number as JsonNumber, Value as JsonValue};
 use std::{
     collections::HashMap,
     convert::TryInto,
+    fmt,
     hash::{Hash, Hasher},
 };
 
@@ -30,25 +38,118 @@ use crate::{
     data_type::Decimal,
     errors::{ParquetError, Result},
     record::{
-        reader::ValueReader,
+        reader::{MapReader, ValueReader},
         schemas::{
-            BoolSchema, BsonSchema, ByteArraySchema, DateSchema, DecimalSchema,
-            EnumSchema, F32Schema, F64Schema, GroupSchema, I16Schema, I32Schema,
-            I64Schema, I8Schema, JsonSchema, ListSchema, ListSchemaType, OptionSchema,
-            StringSchema, TimeSchema, TimestampSchema, U16Schema, U32Schema, U64Schema,
-            U8Schema, ValueSchema,
+            BoolSchema, BsonSchema, ByteArraySchema, DateSchema,
+            DateTimeWithoutTimezoneSchema, DateWithoutTimezoneSchema,
+            DecimalSchema, EnumSchema, F32Schema, F64Schema, GroupSchema, I16Schema,
+            I32Schema, I64Schema, I8Schema, IntervalSchema, IpAddrSchema, JsonSchema,
+            ListSchema, ListSchemaType, MapSchema, OptionSchema, StringSchema,
+            TimeSchema, TimeWithoutTimezoneSchema, TimestampSchema, TimezoneSchema,
+            U16Schema, U32Schema, U64Schema, U8Schema, UrlSchema, ValueSchema,
+            WebpageSchema,
         },
         types::{
-            list::parse_list, map::parse_map, Bson, Date, Downcast, Enum, Group, Json,
-            List, Map, Time, Timestamp, ValueRequired,
+            list::{parse_list, parse_map_key_value_legacy}, map::parse_map, Bson, Date,
+            DateTime,
+            DateTimeWithoutTimezone, DateWithoutTimezone, Downcast, Enum, Group, IpAddr,
+            Json, List, Map, Time, TimeWithoutTimezone, Timestamp, Timezone, Url,
+            ValueRequired, Webpage,
         },
-        Record,
+        Reader, Record,
     },
     schema::types::{ColumnPath, Type},
 };
 
+/// A Parquet `INTERVAL` logical type: a duration expressed as separate months, days and
+/// milliseconds components, per the [spec](https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#interval).
+/// Because months and days aren't a fixed number of milliseconds (months vary in length,
+/// days can gain/lose an hour around DST transitions), the three components are kept
+/// apart rather than folded into a single duration, mirroring an Arrow
+/// `IntervalUnit::MonthDayNano`-style split (`millis` here takes the place of Arrow's
+/// nanoseconds).
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Interval {
+    /// Number of months.
+    pub months: u32,
+    /// Number of days.
+    pub days: u32,
+    /// Number of milliseconds.
+    pub millis: u32,
+}
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} months {} days {} millis",
+            self.months, self.days, self.millis
+        )
+    }
+}
+impl Record for Interval {
+    type Schema = IntervalSchema;
+    type Reader = impl Reader<Item = Self>;
+
+    fn parse(
+        schema: &Type,
+        repetition: Option<Repetition>,
+    ) -> Result<(String, Self::Schema)> {
+        if repetition == Some(Repetition::Required)
+            && schema.is_primitive()
+            && schema.get_physical_type() == PhysicalType::FixedLenByteArray
+            && schema.get_basic_info().logical_type() == LogicalType::Interval
+        {
+            if schema.get_type_length() != 12 {
+                return Err(ParquetError::General(format!(
+                    "Interval requires a FixedLenByteArray of length 12, found length {}",
+                    schema.get_type_length()
+                )));
+            }
+            return Ok((schema.name().to_owned(), IntervalSchema));
+        }
+        Err(ParquetError::General(format!(
+            "Couldn't parse Interval {:?}",
+            schema
+        )))
+    }
+
+    fn reader(
+        _schema: &Self::Schema,
+        path: &mut Vec<String>,
+        def_level: i16,
+        rep_level: i16,
+        paths: &mut HashMap<ColumnPath, ColumnReader>,
+        batch_size: usize,
+    ) -> Self::Reader {
+        MapReader(
+            <Vec<u8> as Record>::reader(
+                &ByteArraySchema(Some(12)),
+                path,
+                def_level,
+                rep_level,
+                paths,
+                batch_size,
+            ),
+            |bytes: Vec<u8>| {
+                if bytes.len() != 12 {
+                    return Err(ParquetError::General(format!(
+                        "Interval value must be 12 bytes, found {}",
+                        bytes.len()
+                    )));
+                }
+                let months = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                let days = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+                let millis =
+                    u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
+                Ok(Interval { months, days, millis })
+            },
+        )
+    }
+}
+
 /// Represents any valid Parquet value.
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Debug)]
 pub enum Value {
     // Primitive types
     /// Boolean value (`true`, `false`).
@@ -92,6 +193,27 @@ pub enum Value {
     Json(Json),
     /// Enum string.
     Enum(Enum),
+    /// Timezone-aware instant, stored as microseconds from the Unix epoch plus an
+    /// offset.
+    DateTime(DateTime),
+    /// Date and time of day without a timezone.
+    DateTimeWithoutTimezone(DateTimeWithoutTimezone),
+    /// Date without a time of day or timezone, stores the number of days from the Unix
+    /// epoch, 1 January 1970.
+    DateWithoutTimezone(DateWithoutTimezone),
+    /// Time of day without a timezone, stores the number of microseconds from
+    /// midnight.
+    TimeWithoutTimezone(TimeWithoutTimezone),
+    /// A timezone offset.
+    Timezone(Timezone),
+    /// IP address, either IPv4 or IPv6.
+    IpAddr(IpAddr),
+    /// URL string.
+    Url(Url),
+    /// HTML webpage.
+    Webpage(Webpage),
+    /// A Parquet `INTERVAL` (months/days/milliseconds) duration.
+    Interval(Interval),
 
     // Complex types
     /// List of elements.
@@ -106,103 +228,468 @@ pub enum Value {
 
 #[allow(clippy::derive_hash_xor_eq)]
 impl Hash for Value {
+    /// Each wrapper forwards directly to its inner value's `Hash` rather than mixing in
+    /// a discriminant, so `Value::U32(x)` hashes identically to `x`, `Value::String(s)`
+    /// identically to `s`, and so on. This is the invariant that lets a typed key and
+    /// its `Value`-wrapped form be used interchangeably as keys into any `Map`: see
+    /// `PartialEq<Map<K, V>> for Value` below, which relies on it to avoid a
+    /// representation mismatch when comparing against a differently-typed map.
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
-            Value::Bool(value) => {
-                0u8.hash(state);
-                value.hash(state);
-            }
-            Value::U8(value) => {
-                1u8.hash(state);
-                value.hash(state);
-            }
-            Value::I8(value) => {
-                2u8.hash(state);
-                value.hash(state);
-            }
-            Value::U16(value) => {
-                3u8.hash(state);
-                value.hash(state);
-            }
-            Value::I16(value) => {
-                4u8.hash(state);
-                value.hash(state);
-            }
-            Value::U32(value) => {
-                5u8.hash(state);
-                value.hash(state);
-            }
-            Value::I32(value) => {
-                6u8.hash(state);
-                value.hash(state);
-            }
-            Value::U64(value) => {
-                7u8.hash(state);
-                value.hash(state);
-            }
-            Value::I64(value) => {
-                8u8.hash(state);
-                value.hash(state);
-            }
-            Value::F32(_value) => {
-                9u8.hash(state);
-            }
-            Value::F64(_value) => {
-                10u8.hash(state);
-            }
-            Value::Date(value) => {
-                11u8.hash(state);
-                value.hash(state);
-            }
-            Value::Time(value) => {
-                12u8.hash(state);
-                value.hash(state);
-            }
-            Value::Timestamp(value) => {
-                13u8.hash(state);
-                value.hash(state);
-            }
-            Value::Decimal(_value) => {
-                14u8.hash(state);
+            Value::Bool(value) => value.hash(state),
+            Value::U8(value) => value.hash(state),
+            Value::I8(value) => value.hash(state),
+            Value::U16(value) => value.hash(state),
+            Value::I16(value) => value.hash(state),
+            Value::U32(value) => value.hash(state),
+            Value::I32(value) => value.hash(state),
+            Value::U64(value) => value.hash(state),
+            Value::I64(value) => value.hash(state),
+            // `f32`/`f64` aren't `Hash` (there's no canonical bit pattern for NaN under
+            // `==`), so hash the bit pattern directly: values `PartialEq` considers equal
+            // (`total_cmp` equal) have equal bits and so hash equally. `-0.0` is also
+            // canonicalised to `+0.0`, which only makes those two unequal values collide.
+            Value::F32(value) => {
+                (if *value == 0.0 { 0f32 } else { *value })
+                    .to_bits()
+                    .hash(state)
             }
-            Value::ByteArray(value) => {
-                15u8.hash(state);
-                value.hash(state);
+            Value::F64(value) => {
+                (if *value == 0.0 { 0f64 } else { *value })
+                    .to_bits()
+                    .hash(state)
             }
-            Value::Bson(value) => {
-                16u8.hash(state);
-                value.hash(state);
+            Value::Date(value) => value.hash(state),
+            Value::Time(value) => value.hash(state),
+            Value::Timestamp(value) => value.hash(state),
+            // `Decimal` isn't `Hash`, so every value collides here; that's a valid, if
+            // weak, hash under the `Hash`/`Eq` contract.
+            Value::Decimal(_value) => (),
+            Value::ByteArray(value) => value.hash(state),
+            Value::Bson(value) => value.hash(state),
+            Value::String(value) => value.hash(state),
+            Value::Json(value) => value.hash(state),
+            Value::Enum(value) => value.hash(state),
+            Value::DateTime(value) => value.hash(state),
+            Value::DateTimeWithoutTimezone(value) => value.hash(state),
+            Value::DateWithoutTimezone(value) => value.hash(state),
+            Value::TimeWithoutTimezone(value) => value.hash(state),
+            Value::Timezone(value) => value.hash(state),
+            Value::IpAddr(value) => value.hash(state),
+            Value::Url(value) => value.hash(state),
+            Value::Webpage(value) => value.hash(state),
+            Value::Interval(value) => value.hash(state),
+            Value::List(value) => value.hash(state),
+            // `Map` is unordered, so entries are combined commutatively rather than
+            // hashed in iteration order, to keep equal maps hashing equally regardless
+            // of their internal entry order.
+            Value::Map(value) => {
+                let combined = value
+                    .0
+                    .iter()
+                    .map(|(k, v)| {
+                        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                        k.hash(&mut hasher);
+                        v.hash(&mut hasher);
+                        hasher.finish()
+                    })
+                    .fold(0u64, |acc, entry| acc ^ entry);
+                combined.hash(state);
             }
-            Value::String(value) => {
-                17u8.hash(state);
-                value.hash(state);
+            Value::Group(value) => value.0.hash(state),
+            Value::Option(value) => value.hash(state),
+        }
+    }
+}
+/// `F32`/`F64` compare via `total_cmp(..) == Equal` rather than raw `==`, to stay
+/// consistent with both `Hash` above (which hashes the bit pattern, so values with equal
+/// bits, NaNs included, hash equally) and `Ord`'s `total_cmp`-based `cmp` below: plain `==` disagrees
+/// with both (`-0.0 == 0.0` yet `cmp` orders them, and `NaN != NaN` yet `Eq` requires
+/// reflexivity), which broke `BTreeSet`/`HashSet` agreement on membership for `Value`.
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a == b,
+            (Value::U8(a), Value::U8(b)) => a == b,
+            (Value::I8(a), Value::I8(b)) => a == b,
+            (Value::U16(a), Value::U16(b)) => a == b,
+            (Value::I16(a), Value::I16(b)) => a == b,
+            (Value::U32(a), Value::U32(b)) => a == b,
+            (Value::I32(a), Value::I32(b)) => a == b,
+            (Value::U64(a), Value::U64(b)) => a == b,
+            (Value::I64(a), Value::I64(b)) => a == b,
+            (Value::F32(a), Value::F32(b)) => a.total_cmp(b) == std::cmp::Ordering::Equal,
+            (Value::F64(a), Value::F64(b)) => a.total_cmp(b) == std::cmp::Ordering::Equal,
+            (Value::Date(a), Value::Date(b)) => a == b,
+            (Value::Time(a), Value::Time(b)) => a == b,
+            (Value::Timestamp(a), Value::Timestamp(b)) => a == b,
+            (Value::Decimal(a), Value::Decimal(b)) => a == b,
+            (Value::ByteArray(a), Value::ByteArray(b)) => a == b,
+            (Value::Bson(a), Value::Bson(b)) => a == b,
+            (Value::String(a), Value::String(b)) => a == b,
+            (Value::Json(a), Value::Json(b)) => a == b,
+            (Value::Enum(a), Value::Enum(b)) => a == b,
+            (Value::DateTime(a), Value::DateTime(b)) => a == b,
+            (Value::DateTimeWithoutTimezone(a), Value::DateTimeWithoutTimezone(b)) => {
+                a == b
             }
-            Value::Json(value) => {
-                18u8.hash(state);
-                value.hash(state);
+            (Value::DateWithoutTimezone(a), Value::DateWithoutTimezone(b)) => a == b,
+            (Value::TimeWithoutTimezone(a), Value::TimeWithoutTimezone(b)) => a == b,
+            (Value::Timezone(a), Value::Timezone(b)) => a == b,
+            (Value::IpAddr(a), Value::IpAddr(b)) => a == b,
+            (Value::Url(a), Value::Url(b)) => a == b,
+            (Value::Webpage(a), Value::Webpage(b)) => a == b,
+            (Value::Interval(a), Value::Interval(b)) => a == b,
+            (Value::List(a), Value::List(b)) => a == b,
+            (Value::Map(a), Value::Map(b)) => a == b,
+            (Value::Group(a), Value::Group(b)) => a == b,
+            (Value::Option(a), Value::Option(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+impl Eq for Value {}
+
+/// A lightweight, `Copy` descriptor of which variant a [`Value`] holds, without the cost
+/// of cloning or matching against the payload. Useful for grouping/bucketing columns,
+/// building dispatch tables, or validating that a column stays monomorphic across rows.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ValueType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    F64,
+    Date,
+    Time,
+    Timestamp,
+    Decimal,
+    ByteArray,
+    Bson,
+    String,
+    Json,
+    Enum,
+    DateTime,
+    DateTimeWithoutTimezone,
+    DateWithoutTimezone,
+    TimeWithoutTimezone,
+    Timezone,
+    IpAddr,
+    Url,
+    Webpage,
+    Interval,
+    List,
+    Map,
+    Group,
+    Option,
+}
+
+impl Value {
+    /// Returns the [`ValueType`] tag for this `Value`, without cloning the payload.
+    pub fn type_of(&self) -> ValueType {
+        match self {
+            Value::Bool(_) => ValueType::Bool,
+            Value::U8(_) => ValueType::U8,
+            Value::I8(_) => ValueType::I8,
+            Value::U16(_) => ValueType::U16,
+            Value::I16(_) => ValueType::I16,
+            Value::U32(_) => ValueType::U32,
+            Value::I32(_) => ValueType::I32,
+            Value::U64(_) => ValueType::U64,
+            Value::I64(_) => ValueType::I64,
+            Value::F32(_) => ValueType::F32,
+            Value::F64(_) => ValueType::F64,
+            Value::Date(_) => ValueType::Date,
+            Value::Time(_) => ValueType::Time,
+            Value::Timestamp(_) => ValueType::Timestamp,
+            Value::Decimal(_) => ValueType::Decimal,
+            Value::ByteArray(_) => ValueType::ByteArray,
+            Value::Bson(_) => ValueType::Bson,
+            Value::String(_) => ValueType::String,
+            Value::Json(_) => ValueType::Json,
+            Value::Enum(_) => ValueType::Enum,
+            Value::DateTime(_) => ValueType::DateTime,
+            Value::DateTimeWithoutTimezone(_) => ValueType::DateTimeWithoutTimezone,
+            Value::DateWithoutTimezone(_) => ValueType::DateWithoutTimezone,
+            Value::TimeWithoutTimezone(_) => ValueType::TimeWithoutTimezone,
+            Value::Timezone(_) => ValueType::Timezone,
+            Value::IpAddr(_) => ValueType::IpAddr,
+            Value::Url(_) => ValueType::Url,
+            Value::Webpage(_) => ValueType::Webpage,
+            Value::Interval(_) => ValueType::Interval,
+            Value::List(_) => ValueType::List,
+            Value::Map(_) => ValueType::Map,
+            Value::Group(_) => ValueType::Group,
+            Value::Option(_) => ValueType::Option,
+        }
+    }
+}
+
+
+/// The stable rank of each [`Value`] variant, used to order mismatched variants and as
+/// the first key when ordering matching variants.
+fn variant_rank(value: &Value) -> u8 {
+    match value {
+        Value::Bool(_) => 0,
+        Value::U8(_) => 1,
+        Value::I8(_) => 2,
+        Value::U16(_) => 3,
+        Value::I16(_) => 4,
+        Value::U32(_) => 5,
+        Value::I32(_) => 6,
+        Value::U64(_) => 7,
+        Value::I64(_) => 8,
+        Value::F32(_) => 9,
+        Value::F64(_) => 10,
+        Value::Date(_) => 11,
+        Value::Time(_) => 12,
+        Value::Timestamp(_) => 13,
+        Value::Decimal(_) => 14,
+        Value::ByteArray(_) => 15,
+        Value::Bson(_) => 16,
+        Value::String(_) => 17,
+        Value::Json(_) => 18,
+        Value::Enum(_) => 19,
+        Value::DateTime(_) => 20,
+        Value::DateTimeWithoutTimezone(_) => 21,
+        Value::DateWithoutTimezone(_) => 22,
+        Value::TimeWithoutTimezone(_) => 23,
+        Value::Timezone(_) => 24,
+        Value::IpAddr(_) => 25,
+        Value::Url(_) => 26,
+        Value::Webpage(_) => 27,
+        Value::Interval(_) => 28,
+        Value::List(_) => 29,
+        Value::Map(_) => 30,
+        Value::Group(_) => 31,
+        Value::Option(_) => 32,
+    }
+}
+
+/// The names of the [`Value`] variants, in the same order as [`variant_rank`], used to
+/// externally tag `Value`'s serde representation so that `Serialize`/`Deserialize` are
+/// exact inverses of one another.
+#[cfg(feature = "serde")]
+const VALUE_VARIANTS: &[&str] = &[
+    "Bool",
+    "U8",
+    "I8",
+    "U16",
+    "I16",
+    "U32",
+    "I32",
+    "U64",
+    "I64",
+    "F32",
+    "F64",
+    "Date",
+    "Time",
+    "Timestamp",
+    "Decimal",
+    "ByteArray",
+    "Bson",
+    "String",
+    "Json",
+    "Enum",
+    "DateTime",
+    "DateTimeWithoutTimezone",
+    "DateWithoutTimezone",
+    "TimeWithoutTimezone",
+    "Timezone",
+    "IpAddr",
+    "Url",
+    "Webpage",
+    "Interval",
+    "List",
+    "Map",
+    "Group",
+    "Option",
+];
+
+/// Identifies which [`Value`] variant a serialized enum tag names, so that
+/// `Deserialize for Value` can dispatch on it with `deserialize_enum` rather than
+/// sniffing the payload's shape. Indices and names are kept in step with
+/// [`variant_rank`]/[`VALUE_VARIANTS`].
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ValueType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueTypeVisitor;
+
+        impl<'de> Visitor<'de> for ValueTypeVisitor {
+            type Value = ValueType;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a Value variant identifier")
             }
-            Value::Enum(value) => {
-                19u8.hash(state);
-                value.hash(state);
+
+            fn visit_u64<E>(self, value: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(match value {
+                    0 => ValueType::Bool,
+                    1 => ValueType::U8,
+                    2 => ValueType::I8,
+                    3 => ValueType::U16,
+                    4 => ValueType::I16,
+                    5 => ValueType::U32,
+                    6 => ValueType::I32,
+                    7 => ValueType::U64,
+                    8 => ValueType::I64,
+                    9 => ValueType::F32,
+                    10 => ValueType::F64,
+                    11 => ValueType::Date,
+                    12 => ValueType::Time,
+                    13 => ValueType::Timestamp,
+                    14 => ValueType::Decimal,
+                    15 => ValueType::ByteArray,
+                    16 => ValueType::Bson,
+                    17 => ValueType::String,
+                    18 => ValueType::Json,
+                    19 => ValueType::Enum,
+                    20 => ValueType::DateTime,
+                    21 => ValueType::DateTimeWithoutTimezone,
+                    22 => ValueType::DateWithoutTimezone,
+                    23 => ValueType::TimeWithoutTimezone,
+                    24 => ValueType::Timezone,
+                    25 => ValueType::IpAddr,
+                    26 => ValueType::Url,
+                    27 => ValueType::Webpage,
+                    28 => ValueType::Interval,
+                    29 => ValueType::List,
+                    30 => ValueType::Map,
+                    31 => ValueType::Group,
+                    32 => ValueType::Option,
+                    _ => {
+                        return Err(E::invalid_value(
+                            Unexpected::Unsigned(value),
+                            &"a Value variant index between 0 and 32",
+                        ))
+                    }
+                })
             }
-            Value::List(value) => {
-                20u8.hash(state);
-                value.hash(state);
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(match value {
+                    "Bool" => ValueType::Bool,
+                    "U8" => ValueType::U8,
+                    "I8" => ValueType::I8,
+                    "U16" => ValueType::U16,
+                    "I16" => ValueType::I16,
+                    "U32" => ValueType::U32,
+                    "I32" => ValueType::I32,
+                    "U64" => ValueType::U64,
+                    "I64" => ValueType::I64,
+                    "F32" => ValueType::F32,
+                    "F64" => ValueType::F64,
+                    "Date" => ValueType::Date,
+                    "Time" => ValueType::Time,
+                    "Timestamp" => ValueType::Timestamp,
+                    "Decimal" => ValueType::Decimal,
+                    "ByteArray" => ValueType::ByteArray,
+                    "Bson" => ValueType::Bson,
+                    "String" => ValueType::String,
+                    "Json" => ValueType::Json,
+                    "Enum" => ValueType::Enum,
+                    "DateTime" => ValueType::DateTime,
+                    "DateTimeWithoutTimezone" => ValueType::DateTimeWithoutTimezone,
+                    "DateWithoutTimezone" => ValueType::DateWithoutTimezone,
+                    "TimeWithoutTimezone" => ValueType::TimeWithoutTimezone,
+                    "Timezone" => ValueType::Timezone,
+                    "IpAddr" => ValueType::IpAddr,
+                    "Url" => ValueType::Url,
+                    "Webpage" => ValueType::Webpage,
+                    "Interval" => ValueType::Interval,
+                    "List" => ValueType::List,
+                    "Map" => ValueType::Map,
+                    "Group" => ValueType::Group,
+                    "Option" => ValueType::Option,
+                    _ => return Err(E::unknown_variant(value, VALUE_VARIANTS)),
+                })
             }
-            Value::Map(_value) => {
-                21u8.hash(state);
+
+            fn visit_bytes<E>(self, value: &[u8]) -> std::result::Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                match std::str::from_utf8(value) {
+                    Ok(value) => self.visit_str(value),
+                    Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+                }
             }
-            Value::Group(_value) => {
-                22u8.hash(state);
+        }
+
+        deserializer.deserialize_identifier(ValueTypeVisitor)
+    }
+}
+
+impl PartialOrd for Value {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for Value {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::U8(a), Value::U8(b)) => a.cmp(b),
+            (Value::I8(a), Value::I8(b)) => a.cmp(b),
+            (Value::U16(a), Value::U16(b)) => a.cmp(b),
+            (Value::I16(a), Value::I16(b)) => a.cmp(b),
+            (Value::U32(a), Value::U32(b)) => a.cmp(b),
+            (Value::I32(a), Value::I32(b)) => a.cmp(b),
+            (Value::U64(a), Value::U64(b)) => a.cmp(b),
+            (Value::I64(a), Value::I64(b)) => a.cmp(b),
+            // IEEE 754 `totalOrder`: `-0.0 < +0.0`, negative NaNs sort below `-inf` and
+            // positive NaNs sort above `+inf`, so every pair of floats is comparable.
+            (Value::F32(a), Value::F32(b)) => a.total_cmp(b),
+            (Value::F64(a), Value::F64(b)) => a.total_cmp(b),
+            (Value::Date(a), Value::Date(b)) => a.cmp(b),
+            (Value::Time(a), Value::Time(b)) => a.cmp(b),
+            (Value::Timestamp(a), Value::Timestamp(b)) => a.cmp(b),
+            (Value::Decimal(a), Value::Decimal(b)) => a.cmp(b),
+            (Value::ByteArray(a), Value::ByteArray(b)) => a.cmp(b),
+            (Value::Bson(a), Value::Bson(b)) => a.cmp(b),
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Json(a), Value::Json(b)) => a.cmp(b),
+            (Value::Enum(a), Value::Enum(b)) => a.cmp(b),
+            (Value::DateTime(a), Value::DateTime(b)) => a.cmp(b),
+            (Value::DateTimeWithoutTimezone(a), Value::DateTimeWithoutTimezone(b)) => {
+                a.cmp(b)
             }
-            Value::Option(value) => {
-                23u8.hash(state);
-                value.hash(state);
+            (Value::DateWithoutTimezone(a), Value::DateWithoutTimezone(b)) => a.cmp(b),
+            (Value::TimeWithoutTimezone(a), Value::TimeWithoutTimezone(b)) => a.cmp(b),
+            (Value::Timezone(a), Value::Timezone(b)) => a.cmp(b),
+            (Value::IpAddr(a), Value::IpAddr(b)) => a.cmp(b),
+            (Value::Url(a), Value::Url(b)) => a.cmp(b),
+            (Value::Webpage(a), Value::Webpage(b)) => a.cmp(b),
+            (Value::Interval(a), Value::Interval(b)) => a.cmp(b),
+            (Value::List(a), Value::List(b)) => a.0.cmp(&b.0),
+            (Value::Map(a), Value::Map(b)) => {
+                let mut a: Vec<_> = a.0.iter().collect();
+                let mut b: Vec<_> = b.0.iter().collect();
+                a.sort();
+                b.sort();
+                a.cmp(&b)
             }
+            (Value::Group(a), Value::Group(b)) => a.cmp(b),
+            (Value::Option(a), Value::Option(b)) => a.cmp(b),
+            (a, b) => variant_rank(a).cmp(&variant_rank(b)),
         }
     }
 }
-impl Eq for Value {}
 
 impl Value {
     /// Returns true if the `Value` is an Bool. Returns false otherwise.
@@ -865,204 +1352,505 @@ impl Value {
         }
     }
 
-    /// Returns true if the `Value` is an List. Returns false otherwise.
-    pub fn is_list(&self) -> bool {
-        if let Value::List(_) = self {
+    /// Returns true if the `Value` is a DateTime. Returns false otherwise.
+    pub fn is_date_time(&self) -> bool {
+        if let Value::DateTime(_) = self {
             true
         } else {
             false
         }
     }
 
-    /// If the `Value` is an List, return a reference to it. Returns Err otherwise.
-    pub fn as_list(&self) -> Result<&List<Value>> {
-        if let Value::List(ret) = self {
+    /// If the `Value` is a DateTime, return a reference to it. Returns Err otherwise.
+    pub fn as_date_time(&self) -> Result<&DateTime> {
+        if let Value::DateTime(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as list",
+                "Cannot access {:?} as date_time",
                 self
             )))
         }
     }
 
-    /// If the `Value` is an List, return it. Returns Err otherwise.
-    pub fn into_list(self) -> Result<List<Value>> {
-        if let Value::List(ret) = self {
+    /// If the `Value` is a DateTime, return it. Returns Err otherwise.
+    pub fn into_date_time(self) -> Result<DateTime> {
+        if let Value::DateTime(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as list",
+                "Cannot access {:?} as date_time",
                 self
             )))
         }
     }
 
-    /// Returns true if the `Value` is an Map. Returns false otherwise.
-    pub fn is_map(&self) -> bool {
-        if let Value::Map(_) = self {
+    /// Returns true if the `Value` is a DateTimeWithoutTimezone. Returns false
+    /// otherwise.
+    pub fn is_date_time_without_timezone(&self) -> bool {
+        if let Value::DateTimeWithoutTimezone(_) = self {
             true
         } else {
             false
         }
     }
 
-    /// If the `Value` is an Map, return a reference to it. Returns Err otherwise.
-    pub fn as_map(&self) -> Result<&Map<Value, Value>> {
-        if let Value::Map(ret) = self {
+    /// If the `Value` is a DateTimeWithoutTimezone, return a reference to it. Returns
+    /// Err otherwise.
+    pub fn as_date_time_without_timezone(&self) -> Result<&DateTimeWithoutTimezone> {
+        if let Value::DateTimeWithoutTimezone(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as map",
+                "Cannot access {:?} as date_time_without_timezone",
                 self
             )))
         }
     }
 
-    /// If the `Value` is an Map, return it. Returns Err otherwise.
-    pub fn into_map(self) -> Result<Map<Value, Value>> {
-        if let Value::Map(ret) = self {
+    /// If the `Value` is a DateTimeWithoutTimezone, return it. Returns Err otherwise.
+    pub fn into_date_time_without_timezone(self) -> Result<DateTimeWithoutTimezone> {
+        if let Value::DateTimeWithoutTimezone(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as map",
+                "Cannot access {:?} as date_time_without_timezone",
                 self
             )))
         }
     }
 
-    /// Returns true if the `Value` is an Group. Returns false otherwise.
-    pub fn is_group(&self) -> bool {
-        if let Value::Group(_) = self {
+    /// Returns true if the `Value` is a DateWithoutTimezone. Returns false otherwise.
+    pub fn is_date_without_timezone(&self) -> bool {
+        if let Value::DateWithoutTimezone(_) = self {
             true
         } else {
             false
         }
     }
 
-    /// If the `Value` is an Group, return a reference to it. Returns Err otherwise.
-    pub fn as_group(&self) -> Result<&Group> {
-        if let Value::Group(ret) = self {
+    /// If the `Value` is a DateWithoutTimezone, return a reference to it. Returns Err
+    /// otherwise.
+    pub fn as_date_without_timezone(&self) -> Result<&DateWithoutTimezone> {
+        if let Value::DateWithoutTimezone(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as group",
+                "Cannot access {:?} as date_without_timezone",
                 self
             )))
         }
     }
 
-    /// If the `Value` is an Group, return it. Returns Err otherwise.
-    pub fn into_group(self) -> Result<Group> {
-        if let Value::Group(ret) = self {
+    /// If the `Value` is a DateWithoutTimezone, return it. Returns Err otherwise.
+    pub fn into_date_without_timezone(self) -> Result<DateWithoutTimezone> {
+        if let Value::DateWithoutTimezone(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as group",
+                "Cannot access {:?} as date_without_timezone",
                 self
             )))
         }
     }
 
-    /// Returns true if the `Value` is an Option. Returns false otherwise.
-    pub fn is_option(&self) -> bool {
-        if let Value::Option(_) = self {
+    /// Returns true if the `Value` is a TimeWithoutTimezone. Returns false otherwise.
+    pub fn is_time_without_timezone(&self) -> bool {
+        if let Value::TimeWithoutTimezone(_) = self {
             true
         } else {
             false
         }
     }
 
-    /// If the `Value` is an Option, return a reference to it. Returns Err otherwise.
-    fn as_option(&self) -> Result<&Option<ValueRequired>> {
-        if let Value::Option(ret) = self {
+    /// If the `Value` is a TimeWithoutTimezone, return a reference to it. Returns Err
+    /// otherwise.
+    pub fn as_time_without_timezone(&self) -> Result<&TimeWithoutTimezone> {
+        if let Value::TimeWithoutTimezone(ret) = self {
             Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as option",
+                "Cannot access {:?} as time_without_timezone",
                 self
             )))
         }
     }
 
-    /// If the `Value` is an Option, return it. Returns Err otherwise.
-    pub fn into_option(self) -> Result<Option<Value>> {
-        if let Value::Option(ret) = self {
-            Ok(ret.map(Into::into))
+    /// If the `Value` is a TimeWithoutTimezone, return it. Returns Err otherwise.
+    pub fn into_time_without_timezone(self) -> Result<TimeWithoutTimezone> {
+        if let Value::TimeWithoutTimezone(ret) = self {
+            Ok(ret)
         } else {
             Err(ParquetError::General(format!(
-                "Cannot access {:?} as option",
+                "Cannot access {:?} as time_without_timezone",
                 self
             )))
         }
     }
-}
 
-impl From<bool> for Value {
-    fn from(value: bool) -> Self {
-        Value::Bool(value)
+    /// Returns true if the `Value` is a Timezone. Returns false otherwise.
+    pub fn is_timezone(&self) -> bool {
+        if let Value::Timezone(_) = self {
+            true
+        } else {
+            false
+        }
     }
-}
-impl From<u8> for Value {
-    fn from(value: u8) -> Self {
-        Value::U8(value)
+
+    /// If the `Value` is a Timezone, return a reference to it. Returns Err otherwise.
+    pub fn as_timezone(&self) -> Result<&Timezone> {
+        if let Value::Timezone(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as timezone",
+                self
+            )))
+        }
     }
-}
-impl From<i8> for Value {
-    fn from(value: i8) -> Self {
-        Value::I8(value)
+
+    /// If the `Value` is a Timezone, return it. Returns Err otherwise.
+    pub fn into_timezone(self) -> Result<Timezone> {
+        if let Value::Timezone(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as timezone",
+                self
+            )))
+        }
     }
-}
-impl From<u16> for Value {
-    fn from(value: u16) -> Self {
-        Value::U16(value)
+
+    /// Returns true if the `Value` is an IpAddr. Returns false otherwise.
+    pub fn is_ip_addr(&self) -> bool {
+        if let Value::IpAddr(_) = self {
+            true
+        } else {
+            false
+        }
     }
-}
-impl From<i16> for Value {
-    fn from(value: i16) -> Self {
-        Value::I16(value)
+
+    /// If the `Value` is an IpAddr, return a reference to it. Returns Err otherwise.
+    pub fn as_ip_addr(&self) -> Result<&IpAddr> {
+        if let Value::IpAddr(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as ip_addr",
+                self
+            )))
+        }
     }
-}
-impl From<u32> for Value {
-    fn from(value: u32) -> Self {
-        Value::U32(value)
+
+    /// If the `Value` is an IpAddr, return it. Returns Err otherwise.
+    pub fn into_ip_addr(self) -> Result<IpAddr> {
+        if let Value::IpAddr(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as ip_addr",
+                self
+            )))
+        }
     }
-}
-impl From<i32> for Value {
-    fn from(value: i32) -> Self {
-        Value::I32(value)
+
+    /// Returns true if the `Value` is a Url. Returns false otherwise.
+    pub fn is_url(&self) -> bool {
+        if let Value::Url(_) = self {
+            true
+        } else {
+            false
+        }
     }
-}
-impl From<u64> for Value {
-    fn from(value: u64) -> Self {
-        Value::U64(value)
+
+    /// If the `Value` is a Url, return a reference to it. Returns Err otherwise.
+    pub fn as_url(&self) -> Result<&Url> {
+        if let Value::Url(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as url",
+                self
+            )))
+        }
     }
-}
-impl From<i64> for Value {
-    fn from(value: i64) -> Self {
-        Value::I64(value)
+
+    /// If the `Value` is a Url, return it. Returns Err otherwise.
+    pub fn into_url(self) -> Result<Url> {
+        if let Value::Url(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as url",
+                self
+            )))
+        }
     }
-}
-impl From<f32> for Value {
-    fn from(value: f32) -> Self {
-        Value::F32(value)
+
+    /// Returns true if the `Value` is a Webpage. Returns false otherwise.
+    pub fn is_webpage(&self) -> bool {
+        if let Value::Webpage(_) = self {
+            true
+        } else {
+            false
+        }
     }
-}
-impl From<f64> for Value {
-    fn from(value: f64) -> Self {
-        Value::F64(value)
+
+    /// If the `Value` is a Webpage, return a reference to it. Returns Err otherwise.
+    pub fn as_webpage(&self) -> Result<&Webpage> {
+        if let Value::Webpage(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as webpage",
+                self
+            )))
+        }
     }
-}
-impl From<Date> for Value {
-    fn from(value: Date) -> Self {
-        Value::Date(value)
+
+    /// If the `Value` is a Webpage, return it. Returns Err otherwise.
+    pub fn into_webpage(self) -> Result<Webpage> {
+        if let Value::Webpage(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as webpage",
+                self
+            )))
+        }
     }
-}
-impl From<Time> for Value {
-    fn from(value: Time) -> Self {
-        Value::Time(value)
+
+    /// Returns true if the `Value` is an Interval. Returns false otherwise.
+    pub fn is_interval(&self) -> bool {
+        if let Value::Interval(_) = self {
+            true
+        } else {
+            false
+        }
     }
-}
+
+    /// If the `Value` is an Interval, return a reference to it. Returns Err otherwise.
+    pub fn as_interval(&self) -> Result<&Interval> {
+        if let Value::Interval(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as interval",
+                self
+            )))
+        }
+    }
+
+    /// If the `Value` is an Interval, return it. Returns Err otherwise.
+    pub fn into_interval(self) -> Result<Interval> {
+        if let Value::Interval(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as interval",
+                self
+            )))
+        }
+    }
+
+    /// Returns true if the `Value` is an List. Returns false otherwise.
+    pub fn is_list(&self) -> bool {
+        if let Value::List(_) = self {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// If the `Value` is an List, return a reference to it. Returns Err otherwise.
+    pub fn as_list(&self) -> Result<&List<Value>> {
+        if let Value::List(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as list",
+                self
+            )))
+        }
+    }
+
+    /// If the `Value` is an List, return it. Returns Err otherwise.
+    pub fn into_list(self) -> Result<List<Value>> {
+        if let Value::List(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as list",
+                self
+            )))
+        }
+    }
+
+    /// Returns true if the `Value` is an Map. Returns false otherwise.
+    pub fn is_map(&self) -> bool {
+        if let Value::Map(_) = self {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// If the `Value` is an Map, return a reference to it. Returns Err otherwise.
+    pub fn as_map(&self) -> Result<&Map<Value, Value>> {
+        if let Value::Map(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as map",
+                self
+            )))
+        }
+    }
+
+    /// If the `Value` is an Map, return it. Returns Err otherwise.
+    pub fn into_map(self) -> Result<Map<Value, Value>> {
+        if let Value::Map(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as map",
+                self
+            )))
+        }
+    }
+
+    /// Returns true if the `Value` is an Group. Returns false otherwise.
+    pub fn is_group(&self) -> bool {
+        if let Value::Group(_) = self {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// If the `Value` is an Group, return a reference to it. Returns Err otherwise.
+    pub fn as_group(&self) -> Result<&Group> {
+        if let Value::Group(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as group",
+                self
+            )))
+        }
+    }
+
+    /// If the `Value` is an Group, return it. Returns Err otherwise.
+    pub fn into_group(self) -> Result<Group> {
+        if let Value::Group(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as group",
+                self
+            )))
+        }
+    }
+
+    /// Returns true if the `Value` is an Option. Returns false otherwise.
+    pub fn is_option(&self) -> bool {
+        if let Value::Option(_) = self {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// If the `Value` is an Option, return a reference to it. Returns Err otherwise.
+    fn as_option(&self) -> Result<&Option<ValueRequired>> {
+        if let Value::Option(ret) = self {
+            Ok(ret)
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as option",
+                self
+            )))
+        }
+    }
+
+    /// If the `Value` is an Option, return it. Returns Err otherwise.
+    pub fn into_option(self) -> Result<Option<Value>> {
+        if let Value::Option(ret) = self {
+            Ok(ret.map(Into::into))
+        } else {
+            Err(ParquetError::General(format!(
+                "Cannot access {:?} as option",
+                self
+            )))
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+impl From<u8> for Value {
+    fn from(value: u8) -> Self {
+        Value::U8(value)
+    }
+}
+impl From<i8> for Value {
+    fn from(value: i8) -> Self {
+        Value::I8(value)
+    }
+}
+impl From<u16> for Value {
+    fn from(value: u16) -> Self {
+        Value::U16(value)
+    }
+}
+impl From<i16> for Value {
+    fn from(value: i16) -> Self {
+        Value::I16(value)
+    }
+}
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::U32(value)
+    }
+}
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::I32(value)
+    }
+}
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::U64(value)
+    }
+}
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::I64(value)
+    }
+}
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::F32(value)
+    }
+}
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::F64(value)
+    }
+}
+impl From<Date> for Value {
+    fn from(value: Date) -> Self {
+        Value::Date(value)
+    }
+}
+impl From<Time> for Value {
+    fn from(value: Time) -> Self {
+        Value::Time(value)
+    }
+}
 impl From<Timestamp> for Value {
     fn from(value: Timestamp) -> Self {
         Value::Timestamp(value)
@@ -1098,6 +1886,51 @@ impl From<Enum> for Value {
         Value::Enum(value)
     }
 }
+impl From<DateTime> for Value {
+    fn from(value: DateTime) -> Self {
+        Value::DateTime(value)
+    }
+}
+impl From<DateTimeWithoutTimezone> for Value {
+    fn from(value: DateTimeWithoutTimezone) -> Self {
+        Value::DateTimeWithoutTimezone(value)
+    }
+}
+impl From<DateWithoutTimezone> for Value {
+    fn from(value: DateWithoutTimezone) -> Self {
+        Value::DateWithoutTimezone(value)
+    }
+}
+impl From<TimeWithoutTimezone> for Value {
+    fn from(value: TimeWithoutTimezone) -> Self {
+        Value::TimeWithoutTimezone(value)
+    }
+}
+impl From<Timezone> for Value {
+    fn from(value: Timezone) -> Self {
+        Value::Timezone(value)
+    }
+}
+impl From<IpAddr> for Value {
+    fn from(value: IpAddr) -> Self {
+        Value::IpAddr(value)
+    }
+}
+impl From<Url> for Value {
+    fn from(value: Url) -> Self {
+        Value::Url(value)
+    }
+}
+impl From<Webpage> for Value {
+    fn from(value: Webpage) -> Self {
+        Value::Webpage(value)
+    }
+}
+impl From<Interval> for Value {
+    fn from(value: Interval) -> Self {
+        Value::Interval(value)
+    }
+}
 impl<T> From<List<T>> for Value
 where
     Value: From<T>,
@@ -1260,6 +2093,67 @@ impl Downcast<Enum> for Value {
         self.into_enum()
     }
 }
+impl Downcast<DateTime> for Value {
+    fn downcast(self) -> Result<DateTime> {
+        self.into_date_time()
+    }
+}
+impl Downcast<DateTimeWithoutTimezone> for Value {
+    fn downcast(self) -> Result<DateTimeWithoutTimezone> {
+        self.into_date_time_without_timezone()
+    }
+}
+impl Downcast<DateWithoutTimezone> for Value {
+    fn downcast(self) -> Result<DateWithoutTimezone> {
+        self.into_date_without_timezone()
+    }
+}
+impl Downcast<TimeWithoutTimezone> for Value {
+    fn downcast(self) -> Result<TimeWithoutTimezone> {
+        self.into_time_without_timezone()
+    }
+}
+// Unlike the other `Downcast` impls above, a Parquet column carrying one of these types is
+// physically just a `String`/`ByteArray` (see `Value::parse`'s Utf8 field-name hints), so
+// downcasting also has to accept those variants, parsing and validating the contents rather
+// than merely requiring the value to already be the target variant.
+impl Downcast<Timezone> for Value {
+    fn downcast(self) -> Result<Timezone> {
+        match self {
+            Value::Timezone(ret) => Ok(ret),
+            value => parse_into(value, "timezone"),
+        }
+    }
+}
+impl Downcast<IpAddr> for Value {
+    fn downcast(self) -> Result<IpAddr> {
+        match self {
+            Value::IpAddr(ret) => Ok(ret),
+            value => parse_into(value, "ip_addr"),
+        }
+    }
+}
+impl Downcast<Url> for Value {
+    fn downcast(self) -> Result<Url> {
+        match self {
+            Value::Url(ret) => Ok(ret),
+            value => parse_into(value, "url"),
+        }
+    }
+}
+impl Downcast<Webpage> for Value {
+    fn downcast(self) -> Result<Webpage> {
+        match self {
+            Value::Webpage(ret) => Ok(ret),
+            value => parse_into(value, "webpage"),
+        }
+    }
+}
+impl Downcast<Interval> for Value {
+    fn downcast(self) -> Result<Interval> {
+        self.into_interval()
+    }
+}
 impl<T> Downcast<List<T>> for Value
 where
     Value: Downcast<T>,
@@ -1279,21 +2173,103 @@ impl Downcast<List<Value>> for Value {
         self.into_list()
     }
 }
-impl<K, V> Downcast<Map<K, V>> for Value
+/// How to resolve a repeated key when building a `Map` from a sequence of key/value
+/// pairs: when downcasting `Value::Map` to a differently-typed `Map<K, V>` (where two
+/// distinct `Value` keys can collide once downcast to `K`), and, always with the default
+/// policy, in [`Value::from_json_value`]. Reading a Parquet `MAP` column doesn't go
+/// through a `MapDuplicatePolicy`: its repeated keys are resolved by `Map`'s own
+/// `Record::reader`.
+///
+/// Collecting pairs directly into a `HashMap` silently resolves collisions in iteration
+/// order, which is both non-deterministic and, for untrusted input, a history of
+/// duplicate-key ambiguity bugs. An explicit policy makes the resolution a deliberate,
+/// documented choice instead.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MapDuplicatePolicy {
+    /// Keep the value from the last occurrence of a repeated key. Implemented by
+    /// folding the pairs from the left, so the simple implementation is also the
+    /// correct one.
+    LastWins,
+    /// Keep the value from the first occurrence of a repeated key.
+    FirstWins,
+    /// Treat a repeated key as malformed input, failing with `ParquetError::General`
+    /// naming the offending key.
+    Error,
+}
+impl Default for MapDuplicatePolicy {
+    /// `LastWins`, matching this crate's historical, undocumented behaviour.
+    fn default() -> Self {
+        MapDuplicatePolicy::LastWins
+    }
+}
+
+/// Folds `pairs` into a `HashMap`, resolving repeated keys according to `policy`.
+fn build_map_with_policy<K, V, I>(
+    pairs: I, policy: MapDuplicatePolicy,
+) -> Result<HashMap<K, V>>
 where
-    Value: Downcast<K> + Downcast<V>,
-    K: Hash + Eq,
+    K: Hash + Eq + fmt::Debug,
+    I: IntoIterator<Item = (K, V)>,
 {
-    default fn downcast(self) -> Result<Map<K, V>> {
+    let mut map = HashMap::new();
+    for (key, value) in pairs {
+        match policy {
+            MapDuplicatePolicy::LastWins => {
+                let _ = map.insert(key, value);
+            }
+            MapDuplicatePolicy::FirstWins => {
+                let _ = map.entry(key).or_insert(value);
+            }
+            MapDuplicatePolicy::Error => match map.entry(key) {
+                std::collections::hash_map::Entry::Occupied(entry) => {
+                    return Err(ParquetError::General(format!(
+                        "Duplicate key {:?} in Parquet MAP",
+                        entry.key()
+                    )));
+                }
+                std::collections::hash_map::Entry::Vacant(entry) => {
+                    let _ = entry.insert(value);
+                }
+            },
+        }
+    }
+    Ok(map)
+}
+
+impl Value {
+    /// Downcasts `Value::Map` into a `Map<K, V>`, resolving any collision introduced by
+    /// two distinct `Value` keys downcasting to the same `K` according to `policy`,
+    /// instead of the [`MapDuplicatePolicy::default()`] the plain `Downcast` impl below
+    /// applies. This is the only way to select `FirstWins`/`Error` on the decode path,
+    /// since `Downcast::downcast` takes no arguments of its own.
+    pub fn downcast_map_with_policy<K, V>(self, policy: MapDuplicatePolicy) -> Result<Map<K, V>>
+    where
+        Value: Downcast<K> + Downcast<V>,
+        K: Hash + Eq + fmt::Debug,
+    {
         self.into_map().and_then(|map| {
             map.0
                 .into_iter()
                 .map(|(k, v)| Ok((k.downcast()?, v.downcast()?)))
-                .collect::<Result<HashMap<_, _>>>()
+                .collect::<Result<Vec<_>>>()
+                .and_then(|pairs| build_map_with_policy(pairs, policy))
                 .map(Map)
         })
     }
 }
+impl<K, V> Downcast<Map<K, V>> for Value
+where
+    Value: Downcast<K> + Downcast<V>,
+    K: Hash + Eq + fmt::Debug,
+{
+    /// Downcasts every key/value pair, resolving any collision introduced by two
+    /// distinct `Value` keys downcasting to the same `K` with
+    /// [`MapDuplicatePolicy::default()`]. Call
+    /// [`Value::downcast_map_with_policy`] directly to choose a different policy.
+    default fn downcast(self) -> Result<Map<K, V>> {
+        self.downcast_map_with_policy(MapDuplicatePolicy::default())
+    }
+}
 impl Downcast<Map<Value, Value>> for Value {
     fn downcast(self) -> Result<Map<Value, Value>> {
         self.into_map()
@@ -1431,6 +2407,67 @@ impl PartialEq<Enum> for Value {
         self.as_enum().map(|enum_| enum_ == other).unwrap_or(false)
     }
 }
+impl PartialEq<DateTime> for Value {
+    fn eq(&self, other: &DateTime) -> bool {
+        self.as_date_time()
+            .map(|date_time| date_time == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<DateTimeWithoutTimezone> for Value {
+    fn eq(&self, other: &DateTimeWithoutTimezone) -> bool {
+        self.as_date_time_without_timezone()
+            .map(|date_time| date_time == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<DateWithoutTimezone> for Value {
+    fn eq(&self, other: &DateWithoutTimezone) -> bool {
+        self.as_date_without_timezone()
+            .map(|date| date == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<TimeWithoutTimezone> for Value {
+    fn eq(&self, other: &TimeWithoutTimezone) -> bool {
+        self.as_time_without_timezone()
+            .map(|time| time == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<Timezone> for Value {
+    fn eq(&self, other: &Timezone) -> bool {
+        self.as_timezone()
+            .map(|timezone| timezone == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<IpAddr> for Value {
+    fn eq(&self, other: &IpAddr) -> bool {
+        self.as_ip_addr()
+            .map(|ip_addr| ip_addr == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<Url> for Value {
+    fn eq(&self, other: &Url) -> bool {
+        self.as_url().map(|url| url == other).unwrap_or(false)
+    }
+}
+impl PartialEq<Webpage> for Value {
+    fn eq(&self, other: &Webpage) -> bool {
+        self.as_webpage()
+            .map(|webpage| webpage == other)
+            .unwrap_or(false)
+    }
+}
+impl PartialEq<Interval> for Value {
+    fn eq(&self, other: &Interval) -> bool {
+        self.as_interval()
+            .map(|interval| interval == other)
+            .unwrap_or(false)
+    }
+}
 impl<T> PartialEq<List<T>> for Value
 where
     Value: PartialEq<T>,
@@ -1439,6 +2476,8 @@ where
         self.as_list().map(|list| list == other).unwrap_or(false)
     }
 }
+/// See the `Hash` impl above for the invariant this and the `List` comparison above
+/// rely on: a typed key/element and its `Value`-wrapped form are interchangeable.
 impl<K, V> PartialEq<Map<K, V>> for Value
 where
     Value: PartialEq<K> + PartialEq<V>,
@@ -1451,17 +2490,15 @@ where
                     return false;
                 }
 
-                // This comparison unfortunately requires a bit of a hack. This could be
-                // eliminated by ensuring that Value::X hashes identically to X. TODO.
-                let other = other
-                    .0
-                    .iter()
-                    .map(|(k, v)| (k.clone().into(), v))
-                    .collect::<HashMap<Value, _>>();
-
-                map.0
-                    .iter()
-                    .all(|(key, value)| other.get(key).map_or(false, |v| value == *v))
+                // `Value::X` hashes and compares identically to `X` (see the `Hash`
+                // impl above), so a key converted into a `Value` looks itself up
+                // directly in `map.0` without either side needing to be rebuilt into a
+                // matching representation first.
+                other.0.iter().all(|(key, value)| {
+                    map.0
+                        .get(&key.clone().into())
+                        .map_or(false, |v| v == value)
+                })
             })
             .unwrap_or(false)
     }
@@ -1486,6 +2523,71 @@ where
     }
 }
 
+/// `ParquetError` doesn't derive `PartialEq` itself (some variants may wrap error types,
+/// e.g. an I/O error, that aren't `PartialEq`), so compare by rendered message instead.
+/// That's enough for tests asserting on the exact error a malformed schema produces,
+/// without taking on the maintenance cost of keeping a structural comparison in sync
+/// with every variant.
+impl PartialEq for ParquetError {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_string() == other.to_string()
+    }
+}
+
+/// Converts a Parquet schema's `precision`/`scale` (signed, per the Thrift definition)
+/// into the unsigned pair `DecimalSchema` stores them as, failing rather than panicking
+/// on a negative or overflowing value from a malformed or adversarial footer.
+fn decimal_precision_scale(precision: i32, scale: i32) -> Result<(u32, u32)> {
+    let precision = precision.try_into().map_err(|_| {
+        ParquetError::General(format!(
+            "Decimal precision {} does not fit in a u32",
+            precision
+        ))
+    })?;
+    let scale = scale.try_into().map_err(|_| {
+        ParquetError::General(format!("Decimal scale {} does not fit in a u32", scale))
+    })?;
+    Ok((precision, scale))
+}
+
+/// Converts a Parquet schema's `type_length` (signed, per the Thrift definition) into
+/// the `usize` `ByteArraySchema`/length checks use, failing rather than panicking on a
+/// negative or overflowing value from a malformed or adversarial footer.
+fn fixed_len_byte_array_length(type_length: i32) -> Result<usize> {
+    type_length.try_into().map_err(|_| {
+        ParquetError::General(format!(
+            "Fixed-length byte array length {} does not fit in a usize",
+            type_length
+        ))
+    })
+}
+
+/// Downcasts a `Value::String`/`Value::ByteArray` into one of the `FromStr` types stored as
+/// plain Utf8 columns (`Timezone`/`IpAddr`/`Url`/`Webpage`), failing with a `ParquetError`
+/// that names the target and the malformed input rather than panicking. Any other `Value`
+/// variant is rejected outright, same as the other `Downcast` impls in this file.
+fn parse_into<T>(value: Value, target: &str) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    let string = match value {
+        Value::String(string) => string,
+        Value::ByteArray(bytes) => String::from_utf8(bytes).map_err(|err| {
+            ParquetError::General(format!("Invalid {} bytes: {}", target, err))
+        })?,
+        value => {
+            return Err(ParquetError::General(format!(
+                "Cannot access {:?} as {}",
+                value, target
+            )))
+        }
+    };
+    string
+        .parse()
+        .map_err(|err| ParquetError::General(format!("Invalid {} {:?}: {}", target, string, err)))
+}
+
 impl Record for Value {
     type Schema = ValueSchema;
     type Reader = ValueReader;
@@ -1529,13 +2631,19 @@ impl Record for Value {
                         ValueSchema::Date(DateSchema)
                     }
                     (PhysicalType::Int32, LogicalType::TimeMillis) => {
-                        ValueSchema::Time(TimeSchema::Millis)
+                        if schema.get_basic_info().is_adjusted_to_utc().unwrap_or(true) {
+                            ValueSchema::Time(TimeSchema::Millis)
+                        } else {
+                            ValueSchema::TimeWithoutTimezone(
+                                TimeWithoutTimezoneSchema::Millis,
+                            )
+                        }
                     }
                     (PhysicalType::Int32, LogicalType::Decimal) => {
-                        let (precision, scale) =
-                            (schema.get_precision(), schema.get_scale());
-                        let (precision, scale) =
-                            (precision.try_into().unwrap(), scale.try_into().unwrap());
+                        let (precision, scale) = decimal_precision_scale(
+                            schema.get_precision(),
+                            schema.get_scale(),
+                        )?;
                         ValueSchema::Decimal(DecimalSchema::Int32 { precision, scale })
                     }
                     (PhysicalType::Int64, LogicalType::Uint64) => {
@@ -1546,19 +2654,37 @@ impl Record for Value {
                         ValueSchema::I64(I64Schema)
                     }
                     (PhysicalType::Int64, LogicalType::TimeMicros) => {
-                        ValueSchema::Time(TimeSchema::Micros)
+                        if schema.get_basic_info().is_adjusted_to_utc().unwrap_or(true) {
+                            ValueSchema::Time(TimeSchema::Micros)
+                        } else {
+                            ValueSchema::TimeWithoutTimezone(
+                                TimeWithoutTimezoneSchema::Micros,
+                            )
+                        }
                     }
                     (PhysicalType::Int64, LogicalType::TimestampMillis) => {
-                        ValueSchema::Timestamp(TimestampSchema::Millis)
+                        if schema.get_basic_info().is_adjusted_to_utc().unwrap_or(true) {
+                            ValueSchema::Timestamp(TimestampSchema::Millis)
+                        } else {
+                            ValueSchema::DateTimeWithoutTimezone(
+                                DateTimeWithoutTimezoneSchema::Millis,
+                            )
+                        }
                     }
                     (PhysicalType::Int64, LogicalType::TimestampMicros) => {
-                        ValueSchema::Timestamp(TimestampSchema::Micros)
+                        if schema.get_basic_info().is_adjusted_to_utc().unwrap_or(true) {
+                            ValueSchema::Timestamp(TimestampSchema::Micros)
+                        } else {
+                            ValueSchema::DateTimeWithoutTimezone(
+                                DateTimeWithoutTimezoneSchema::Micros,
+                            )
+                        }
                     }
                     (PhysicalType::Int64, LogicalType::Decimal) => {
-                        let (precision, scale) =
-                            (schema.get_precision(), schema.get_scale());
-                        let (precision, scale) =
-                            (precision.try_into().unwrap(), scale.try_into().unwrap());
+                        let (precision, scale) = decimal_precision_scale(
+                            schema.get_precision(),
+                            schema.get_scale(),
+                        )?;
                         ValueSchema::Decimal(DecimalSchema::Int64 { precision, scale })
                     }
                     (PhysicalType::Int96, LogicalType::None) => {
@@ -1572,15 +2698,33 @@ impl Record for Value {
                     }
                     (PhysicalType::ByteArray, LogicalType::Utf8)
                     | (PhysicalType::FixedLenByteArray, LogicalType::Utf8) => {
-                        ValueSchema::String(StringSchema(ByteArraySchema(
+                        let string_schema = StringSchema(ByteArraySchema(
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
-                        )))
+                        ));
+                        // Parquet has no logical type of its own for these, so a Utf8 column
+                        // is only ever parsed as one of them if its field name says so; any
+                        // other name keeps falling back to a plain `String`.
+                        match schema.name() {
+                            "ip" | "ip_addr" | "ip_address" => {
+                                ValueSchema::IpAddr(IpAddrSchema(string_schema))
+                            }
+                            "url" => ValueSchema::Url(UrlSchema(string_schema)),
+                            "webpage" | "html" => {
+                                ValueSchema::Webpage(WebpageSchema(string_schema))
+                            }
+                            "timezone" | "tz" => {
+                                ValueSchema::Timezone(TimezoneSchema(string_schema))
+                            }
+                            _ => ValueSchema::String(string_schema),
+                        }
                     }
                     (PhysicalType::ByteArray, LogicalType::Json)
                     | (PhysicalType::FixedLenByteArray, LogicalType::Json) => {
@@ -1588,7 +2732,9 @@ impl Record for Value {
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
@@ -1600,7 +2746,9 @@ impl Record for Value {
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
@@ -1612,7 +2760,9 @@ impl Record for Value {
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
@@ -1624,7 +2774,9 @@ impl Record for Value {
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
@@ -1636,24 +2788,36 @@ impl Record for Value {
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
                         );
-                        let (precision, scale) =
-                            (schema.get_precision(), schema.get_scale());
-                        let (precision, scale) =
-                            (precision.try_into().unwrap(), scale.try_into().unwrap());
+                        let (precision, scale) = decimal_precision_scale(
+                            schema.get_precision(),
+                            schema.get_scale(),
+                        )?;
                         ValueSchema::Decimal(DecimalSchema::Array {
                             byte_array_schema,
                             precision,
                             scale,
                         })
                     }
-                    (PhysicalType::ByteArray, LogicalType::Interval)
-                    | (PhysicalType::FixedLenByteArray, LogicalType::Interval) => {
-                        unimplemented!("Interval logical type not yet implemented")
+                    (PhysicalType::FixedLenByteArray, LogicalType::Interval) => {
+                        if schema.get_type_length() != 12 {
+                            return Err(ParquetError::General(format!(
+                                "Interval requires a FixedLenByteArray of length 12, found length {}",
+                                schema.get_type_length()
+                            )));
+                        }
+                        ValueSchema::Interval(IntervalSchema)
+                    }
+                    (PhysicalType::ByteArray, LogicalType::Interval) => {
+                        return Err(ParquetError::General(String::from(
+                            "Interval requires a FixedLenByteArray, found a ByteArray",
+                        )));
                     }
 
                     // Fallbacks for unrecognised LogicalType
@@ -1671,7 +2835,9 @@ impl Record for Value {
                             if schema.get_physical_type()
                                 == PhysicalType::FixedLenByteArray
                             {
-                                Some(schema.get_type_length().try_into().unwrap())
+                                Some(fixed_len_byte_array_length(
+                                    schema.get_type_length(),
+                                )?)
                             } else {
                                 None
                             },
@@ -1688,10 +2854,13 @@ impl Record for Value {
                 .map(|value| ValueSchema::List(Box::new(value)));
         }
 
-        // Try parsing as a map
+        // Try parsing as a map: the modern `MAP`-annotated three-level encoding first,
+        // falling back to the legacy `MAP_KEY_VALUE`-annotated encoding written by older
+        // writers that predate the `MAP` logical type.
         if repetition.is_some() && value.is_none() {
             value = parse_map::<Value, Value>(schema)
                 .ok()
+                .or_else(|| parse_map_key_value_legacy::<Value, Value>(schema).ok())
                 .map(|value| ValueSchema::Map(Box::new(value)));
         }
 
@@ -1724,7 +2893,11 @@ impl Record for Value {
         })?;
 
         // Account for the repetition level
-        match repetition.unwrap() {
+        match repetition.ok_or_else(|| {
+            ParquetError::General(String::from(
+                "Can't parse value: top-level field is missing a repetition",
+            ))
+        })? {
             Repetition::Optional => {
                 value = ValueSchema::Option(Box::new(OptionSchema(value)));
             }
@@ -1818,12 +2991,61 @@ impl Record for Value {
             ValueSchema::Enum(ref schema) => ValueReader::Enum(<Enum as Record>::reader(
                 schema, path, def_level, rep_level, paths, batch_size,
             )),
+            ValueSchema::DateTime(ref schema) => {
+                ValueReader::DateTime(<DateTime as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
+            ValueSchema::DateTimeWithoutTimezone(ref schema) => {
+                ValueReader::DateTimeWithoutTimezone(
+                    <DateTimeWithoutTimezone as Record>::reader(
+                        schema, path, def_level, rep_level, paths, batch_size,
+                    ),
+                )
+            }
+            ValueSchema::DateWithoutTimezone(ref schema) => {
+                ValueReader::DateWithoutTimezone(<DateWithoutTimezone as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
+            ValueSchema::TimeWithoutTimezone(ref schema) => {
+                ValueReader::TimeWithoutTimezone(<TimeWithoutTimezone as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
+            ValueSchema::Timezone(ref schema) => {
+                ValueReader::Timezone(<Timezone as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
+            ValueSchema::IpAddr(ref schema) => {
+                ValueReader::IpAddr(<IpAddr as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
+            ValueSchema::Url(ref schema) => ValueReader::Url(<Url as Record>::reader(
+                schema, path, def_level, rep_level, paths, batch_size,
+            )),
+            ValueSchema::Webpage(ref schema) => {
+                ValueReader::Webpage(<Webpage as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
+            ValueSchema::Interval(ref schema) => {
+                ValueReader::Interval(<Interval as Record>::reader(
+                    schema, path, def_level, rep_level, paths, batch_size,
+                ))
+            }
             ValueSchema::List(ref schema) => {
                 ValueReader::List(Box::new(<List<Value> as Record>::reader(
                     schema, path, def_level, rep_level, paths, batch_size,
                 )))
             }
             ValueSchema::Map(ref schema) => {
+                // Duplicate Parquet MAP keys are resolved by `Map<Value, Value>`'s own
+                // `Record::reader`, not here; `MapDuplicatePolicy` only governs the
+                // `Downcast` step further below, which runs after a `Map` has already
+                // been fully read off the wire.
                 ValueReader::Map(Box::new(<Map<Value, Value> as Record>::reader(
                     schema, path, def_level, rep_level, paths, batch_size,
                 )))
@@ -1841,3 +3063,999 @@ impl Record for Value {
         }
     }
 }
+
+// Serde support, behind the optional `serde` feature. Numerics are externally tagged by
+// variant name so that e.g. `U8` and `I32` round-trip as distinct types;
+// `Date`/`Time`/`Timestamp`/`Decimal` serialize as their own structured payload rather
+// than being flattened away, and `ByteArray`/`Bson` as byte sequences. `List`/`Map`/
+// `Group`/`Option` recurse.
+
+/// Borrows a [`Value::Map`]'s payload for serializing, since `Map<K, V>` itself isn't
+/// `Serialize` (it's defined outside this crate fragment).
+#[cfg(feature = "serde")]
+struct SerializeMapPayload<'a>(&'a Map<Value, Value>);
+#[cfg(feature = "serde")]
+impl<'a> Serialize for SerializeMapPayload<'a> {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.0 .0.len()))?;
+        for (k, v) in &self.0 .0 {
+            map.serialize_entry(k, v)?;
+        }
+        map.end()
+    }
+}
+/// Deserializes a [`Value::Map`]'s payload, mirroring [`SerializeMapPayload`] for the same
+/// reason: `Map<K, V>` isn't `Deserialize` itself.
+#[cfg(feature = "serde")]
+struct DeserializeMapPayload(Map<Value, Value>);
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DeserializeMapPayload {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MapPayloadVisitor;
+
+        impl<'de> Visitor<'de> for MapPayloadVisitor {
+            type Value = DeserializeMapPayload;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a map of Parquet key/value pairs")
+            }
+
+            fn visit_map<A>(
+                self, mut access: A,
+            ) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut values = HashMap::with_capacity(access.size_hint().unwrap_or(0));
+                while let Some((key, value)) = access.next_entry()? {
+                    let _ = values.insert(key, value);
+                }
+                Ok(DeserializeMapPayload(Map(values)))
+            }
+        }
+
+        deserializer.deserialize_map(MapPayloadVisitor)
+    }
+}
+
+/// `Value` is externally tagged under the hood (every variant, including the complex
+/// ones, goes through [`Serializer::serialize_newtype_variant`]) so that `Deserialize`
+/// can dispatch on the tag with `deserialize_enum` rather than guessing a variant from the
+/// shape of the payload alone, which is ambiguous: `Group` and `Map` both serialize as a
+/// plain map, a bare JSON number can't tell `U8` from `U32` apart, and so on. This makes
+/// `Serialize`/`Deserialize` exact inverses: round-tripping a `Value` through them always
+/// reproduces the original variant and physical type.
+///
+/// `ValueRequired`'s own `Serialize`/`Deserialize` can't be derived here: the type is
+/// defined in a module this checkout doesn't contain. `Value::Option` doesn't need it to
+/// round-trip correctly though, since it converts to/from `Option<Value>` via the
+/// existing `From` impls below, and it's `Option<Value>` that's actually serialized.
+#[cfg(feature = "serde")]
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Value::Bool(value) => {
+                serializer.serialize_newtype_variant("Value", 0, "Bool", value)
+            }
+            Value::U8(value) => serializer.serialize_newtype_variant("Value", 1, "U8", value),
+            Value::I8(value) => serializer.serialize_newtype_variant("Value", 2, "I8", value),
+            Value::U16(value) => {
+                serializer.serialize_newtype_variant("Value", 3, "U16", value)
+            }
+            Value::I16(value) => {
+                serializer.serialize_newtype_variant("Value", 4, "I16", value)
+            }
+            Value::U32(value) => {
+                serializer.serialize_newtype_variant("Value", 5, "U32", value)
+            }
+            Value::I32(value) => {
+                serializer.serialize_newtype_variant("Value", 6, "I32", value)
+            }
+            Value::U64(value) => {
+                serializer.serialize_newtype_variant("Value", 7, "U64", value)
+            }
+            Value::I64(value) => {
+                serializer.serialize_newtype_variant("Value", 8, "I64", value)
+            }
+            Value::F32(value) => {
+                serializer.serialize_newtype_variant("Value", 9, "F32", value)
+            }
+            Value::F64(value) => {
+                serializer.serialize_newtype_variant("Value", 10, "F64", value)
+            }
+            Value::Date(value) => {
+                serializer.serialize_newtype_variant("Value", 11, "Date", value)
+            }
+            Value::Time(value) => {
+                serializer.serialize_newtype_variant("Value", 12, "Time", value)
+            }
+            Value::Timestamp(value) => {
+                serializer.serialize_newtype_variant("Value", 13, "Timestamp", value)
+            }
+            Value::Decimal(value) => {
+                serializer.serialize_newtype_variant("Value", 14, "Decimal", value)
+            }
+            Value::ByteArray(value) => {
+                serializer.serialize_newtype_variant("Value", 15, "ByteArray", value)
+            }
+            Value::Bson(value) => {
+                serializer.serialize_newtype_variant("Value", 16, "Bson", value)
+            }
+            Value::String(value) => {
+                serializer.serialize_newtype_variant("Value", 17, "String", value)
+            }
+            Value::Json(value) => {
+                serializer.serialize_newtype_variant("Value", 18, "Json", value)
+            }
+            Value::Enum(value) => {
+                serializer.serialize_newtype_variant("Value", 19, "Enum", value)
+            }
+            Value::DateTime(value) => {
+                serializer.serialize_newtype_variant("Value", 20, "DateTime", value)
+            }
+            Value::DateTimeWithoutTimezone(value) => serializer.serialize_newtype_variant(
+                "Value",
+                21,
+                "DateTimeWithoutTimezone",
+                value,
+            ),
+            Value::DateWithoutTimezone(value) => serializer.serialize_newtype_variant(
+                "Value",
+                22,
+                "DateWithoutTimezone",
+                value,
+            ),
+            Value::TimeWithoutTimezone(value) => serializer.serialize_newtype_variant(
+                "Value",
+                23,
+                "TimeWithoutTimezone",
+                value,
+            ),
+            Value::Timezone(value) => {
+                serializer.serialize_newtype_variant("Value", 24, "Timezone", value)
+            }
+            Value::IpAddr(value) => {
+                serializer.serialize_newtype_variant("Value", 25, "IpAddr", value)
+            }
+            Value::Url(value) => {
+                serializer.serialize_newtype_variant("Value", 26, "Url", value)
+            }
+            Value::Webpage(value) => {
+                serializer.serialize_newtype_variant("Value", 27, "Webpage", value)
+            }
+            Value::Interval(value) => {
+                serializer.serialize_newtype_variant("Value", 28, "Interval", value)
+            }
+            Value::List(value) => serializer.serialize_newtype_variant("Value", 29, "List", value),
+            Value::Map(value) => serializer.serialize_newtype_variant(
+                "Value",
+                30,
+                "Map",
+                &SerializeMapPayload(value),
+            ),
+            Value::Group(value) => {
+                serializer.serialize_newtype_variant("Value", 31, "Group", value)
+            }
+            Value::Option(value) => {
+                let value: Option<Value> = value.clone().map(Into::into);
+                serializer.serialize_newtype_variant("Value", 32, "Option", &value)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ValueVisitor;
+
+        impl<'de> Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a Parquet Value")
+            }
+
+            fn visit_enum<A>(self, data: A) -> std::result::Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (variant_type, variant) = data.variant::<ValueType>()?;
+                Ok(match variant_type {
+                    ValueType::Bool => Value::Bool(variant.newtype_variant()?),
+                    ValueType::U8 => Value::U8(variant.newtype_variant()?),
+                    ValueType::I8 => Value::I8(variant.newtype_variant()?),
+                    ValueType::U16 => Value::U16(variant.newtype_variant()?),
+                    ValueType::I16 => Value::I16(variant.newtype_variant()?),
+                    ValueType::U32 => Value::U32(variant.newtype_variant()?),
+                    ValueType::I32 => Value::I32(variant.newtype_variant()?),
+                    ValueType::U64 => Value::U64(variant.newtype_variant()?),
+                    ValueType::I64 => Value::I64(variant.newtype_variant()?),
+                    ValueType::F32 => Value::F32(variant.newtype_variant()?),
+                    ValueType::F64 => Value::F64(variant.newtype_variant()?),
+                    ValueType::Date => Value::Date(variant.newtype_variant()?),
+                    ValueType::Time => Value::Time(variant.newtype_variant()?),
+                    ValueType::Timestamp => Value::Timestamp(variant.newtype_variant()?),
+                    ValueType::Decimal => Value::Decimal(variant.newtype_variant()?),
+                    ValueType::ByteArray => Value::ByteArray(variant.newtype_variant()?),
+                    ValueType::Bson => Value::Bson(variant.newtype_variant()?),
+                    ValueType::String => Value::String(variant.newtype_variant()?),
+                    ValueType::Json => Value::Json(variant.newtype_variant()?),
+                    ValueType::Enum => Value::Enum(variant.newtype_variant()?),
+                    ValueType::DateTime => Value::DateTime(variant.newtype_variant()?),
+                    ValueType::DateTimeWithoutTimezone => {
+                        Value::DateTimeWithoutTimezone(variant.newtype_variant()?)
+                    }
+                    ValueType::DateWithoutTimezone => {
+                        Value::DateWithoutTimezone(variant.newtype_variant()?)
+                    }
+                    ValueType::TimeWithoutTimezone => {
+                        Value::TimeWithoutTimezone(variant.newtype_variant()?)
+                    }
+                    ValueType::Timezone => Value::Timezone(variant.newtype_variant()?),
+                    ValueType::IpAddr => Value::IpAddr(variant.newtype_variant()?),
+                    ValueType::Url => Value::Url(variant.newtype_variant()?),
+                    ValueType::Webpage => Value::Webpage(variant.newtype_variant()?),
+                    ValueType::Interval => Value::Interval(variant.newtype_variant()?),
+                    ValueType::List => Value::List(variant.newtype_variant()?),
+                    ValueType::Map => {
+                        Value::Map(variant.newtype_variant::<DeserializeMapPayload>()?.0)
+                    }
+                    ValueType::Group => Value::Group(variant.newtype_variant()?),
+                    ValueType::Option => {
+                        let value = variant.newtype_variant::<Option<Value>>()?;
+                        Value::from(value)
+                    }
+                })
+            }
+        }
+
+        deserializer.deserialize_enum("Value", VALUE_VARIANTS, ValueVisitor)
+    }
+}
+
+/// A [`ValueSchema`] that is progressively narrowed from a stream of sample [`Value`]s,
+/// for use when the schema of a data source isn't known ahead of time (e.g. inferring a
+/// writer schema from heterogeneous JSON-derived data).
+///
+/// Start from [`SchemaIncomplete::Unknown`] and call [`merge()`](Self::merge) with each
+/// sample in turn; once every slot has been populated by at least one sample, call
+/// [`resolve()`](Self::resolve) to obtain a concrete [`ValueSchema`].
+#[derive(Clone, Debug)]
+pub enum SchemaIncomplete {
+    /// No sample has constrained this slot yet.
+    Unknown,
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F64,
+    Date,
+    Time,
+    Timestamp,
+    Decimal,
+    ByteArray,
+    Bson,
+    String,
+    Json,
+    Enum,
+    /// The merged schema of every element observed so far.
+    List(Box<SchemaIncomplete>),
+    /// The merged schemas of every key and value observed so far.
+    Map(Box<SchemaIncomplete>, Box<SchemaIncomplete>),
+    /// Field names in first-seen order, their per-field schema, and how many of the
+    /// `seen` samples supplied each field (used to detect fields that should be
+    /// `Option` because they were absent from some samples).
+    Group {
+        fields: LinkedHashMap<String, usize>,
+        schemas: Vec<SchemaIncomplete>,
+        field_seen: Vec<usize>,
+        seen: usize,
+    },
+    Option(Box<SchemaIncomplete>),
+}
+
+impl Default for SchemaIncomplete {
+    fn default() -> Self {
+        SchemaIncomplete::Unknown
+    }
+}
+
+impl SchemaIncomplete {
+    /// Builds a fresh [`SchemaIncomplete`] matching the shape of `value`, recursing into
+    /// `List`/`Map`/`Group`/`Option`.
+    fn from_value(value: &Value) -> Self {
+        match value {
+            Value::Bool(_) => SchemaIncomplete::Bool,
+            Value::U8(_) => SchemaIncomplete::U8,
+            Value::I8(_) => SchemaIncomplete::I8,
+            Value::U16(_) => SchemaIncomplete::U16,
+            Value::I16(_) => SchemaIncomplete::I16,
+            Value::U32(_) => SchemaIncomplete::U32,
+            Value::I32(_) => SchemaIncomplete::I32,
+            Value::U64(_) => SchemaIncomplete::U64,
+            Value::I64(_) => SchemaIncomplete::I64,
+            Value::F32(_) | Value::F64(_) => SchemaIncomplete::F64,
+            Value::Date(_) => SchemaIncomplete::Date,
+            Value::Time(_) => SchemaIncomplete::Time,
+            Value::Timestamp(_) => SchemaIncomplete::Timestamp,
+            Value::Decimal(_) => SchemaIncomplete::Decimal,
+            Value::ByteArray(_) => SchemaIncomplete::ByteArray,
+            Value::Bson(_) => SchemaIncomplete::Bson,
+            Value::String(_) => SchemaIncomplete::String,
+            Value::Json(_) => SchemaIncomplete::Json,
+            Value::Enum(_) => SchemaIncomplete::Enum,
+            Value::List(list) => {
+                let mut element = SchemaIncomplete::Unknown;
+                for item in list.iter() {
+                    element.merge(item);
+                }
+                SchemaIncomplete::List(Box::new(element))
+            }
+            Value::Map(map) => {
+                let mut key = SchemaIncomplete::Unknown;
+                let mut value = SchemaIncomplete::Unknown;
+                for (k, v) in map.0.iter() {
+                    key.merge(k);
+                    value.merge(v);
+                }
+                SchemaIncomplete::Map(Box::new(key), Box::new(value))
+            }
+            Value::Group(group) => {
+                let fields = (*group.field_names()).clone();
+                let schemas = group
+                    .clone()
+                    .into_fields()
+                    .iter()
+                    .map(SchemaIncomplete::from_value)
+                    .collect::<Vec<_>>();
+                let field_seen = vec![1; schemas.len()];
+                SchemaIncomplete::Group {
+                    fields: fields.into_iter().collect(),
+                    schemas,
+                    field_seen,
+                    seen: 1,
+                }
+            }
+            Value::Option(None) => {
+                SchemaIncomplete::Option(Box::new(SchemaIncomplete::Unknown))
+            }
+            Value::Option(Some(value)) => SchemaIncomplete::Option(Box::new(
+                SchemaIncomplete::from_value(&Value::from(value.clone())),
+            )),
+            // These richer temporal/semantic variants aren't yet represented in
+            // `SchemaIncomplete`; fall back to treating them opaquely via their nearest
+            // primitive so inference can still make progress.
+            Value::DateTime(_)
+            | Value::DateTimeWithoutTimezone(_)
+            | Value::DateWithoutTimezone(_)
+            | Value::TimeWithoutTimezone(_)
+            | Value::Timezone(_)
+            | Value::IpAddr(_)
+            | Value::Url(_)
+            | Value::Webpage(_)
+            | Value::Interval(_) => SchemaIncomplete::String,
+        }
+    }
+
+    /// Narrows `self` to also account for `value`.
+    pub fn merge(&mut self, value: &Value) {
+        if let SchemaIncomplete::Unknown = self {
+            *self = SchemaIncomplete::from_value(value);
+            return;
+        }
+        match (self, value) {
+            (SchemaIncomplete::Option(inner), Value::Option(Some(value))) => {
+                inner.merge(&Value::from(value.clone()));
+            }
+            (this @ SchemaIncomplete::Option(_), Value::Option(None)) => {
+                let _ = this;
+            }
+            (this, Value::Option(None)) => {
+                let previous = std::mem::replace(this, SchemaIncomplete::Unknown);
+                *this = SchemaIncomplete::Option(Box::new(previous));
+            }
+            (this, Value::Option(Some(value))) => {
+                this.merge(&Value::from(value.clone()));
+            }
+            (
+                this @ (SchemaIncomplete::U8
+                | SchemaIncomplete::I8
+                | SchemaIncomplete::U16
+                | SchemaIncomplete::I16
+                | SchemaIncomplete::U32
+                | SchemaIncomplete::I32
+                | SchemaIncomplete::U64
+                | SchemaIncomplete::I64),
+                Value::F32(_) | Value::F64(_),
+            ) => {
+                *this = SchemaIncomplete::F64;
+            }
+            (SchemaIncomplete::List(element), Value::List(list)) => {
+                for item in list.iter() {
+                    element.merge(item);
+                }
+            }
+            (SchemaIncomplete::Map(key_schema, value_schema), Value::Map(map)) => {
+                for (k, v) in map.0.iter() {
+                    key_schema.merge(k);
+                    value_schema.merge(v);
+                }
+            }
+            (
+                SchemaIncomplete::Group {
+                    fields,
+                    schemas,
+                    field_seen,
+                    seen,
+                },
+                Value::Group(group),
+            ) => {
+                *seen += 1;
+                for (name, &index) in group.field_names().iter() {
+                    let value = &group.clone().into_fields()[index];
+                    match fields.get(name) {
+                        Some(&slot) => {
+                            schemas[slot].merge(value);
+                            field_seen[slot] += 1;
+                        }
+                        None => {
+                            let slot = schemas.len();
+                            let _ = fields.insert(name.clone(), slot);
+                            schemas.push(SchemaIncomplete::from_value(value));
+                            field_seen.push(1);
+                        }
+                    }
+                }
+            }
+            // A mismatched, non-widenable pair of samples for the same slot; keep the
+            // first schema observed rather than erroring, consistent with `merge` only
+            // ever narrowing, never failing.
+            (_this, _value) => (),
+        }
+    }
+
+    /// Finalises inference into a concrete [`ValueSchema`]. Fails if any slot (this one
+    /// or a nested one) was never populated by a sample.
+    pub fn resolve(self) -> Result<ValueSchema> {
+        Ok(match self {
+            SchemaIncomplete::Unknown => {
+                return Err(ParquetError::General(String::from(
+                    "Can't resolve schema: no sample values were observed for this slot",
+                )));
+            }
+            SchemaIncomplete::Bool => ValueSchema::Bool(BoolSchema),
+            SchemaIncomplete::U8 => ValueSchema::U8(U8Schema),
+            SchemaIncomplete::I8 => ValueSchema::I8(I8Schema),
+            SchemaIncomplete::U16 => ValueSchema::U16(U16Schema),
+            SchemaIncomplete::I16 => ValueSchema::I16(I16Schema),
+            SchemaIncomplete::U32 => ValueSchema::U32(U32Schema),
+            SchemaIncomplete::I32 => ValueSchema::I32(I32Schema),
+            SchemaIncomplete::U64 => ValueSchema::U64(U64Schema),
+            SchemaIncomplete::I64 => ValueSchema::I64(I64Schema),
+            SchemaIncomplete::F64 => ValueSchema::F64(F64Schema),
+            SchemaIncomplete::Date => ValueSchema::Date(DateSchema),
+            SchemaIncomplete::Time => ValueSchema::Time(TimeSchema::Micros),
+            SchemaIncomplete::Timestamp => {
+                ValueSchema::Timestamp(TimestampSchema::Micros)
+            }
+            SchemaIncomplete::Decimal => {
+                ValueSchema::Decimal(DecimalSchema::Int64 {
+                    precision: 0,
+                    scale: 0,
+                })
+            }
+            SchemaIncomplete::ByteArray => ValueSchema::ByteArray(ByteArraySchema(None)),
+            SchemaIncomplete::Bson => ValueSchema::Bson(BsonSchema(ByteArraySchema(None))),
+            SchemaIncomplete::String => {
+                ValueSchema::String(StringSchema(ByteArraySchema(None)))
+            }
+            SchemaIncomplete::Json => {
+                ValueSchema::Json(JsonSchema(StringSchema(ByteArraySchema(None))))
+            }
+            SchemaIncomplete::Enum => {
+                ValueSchema::Enum(EnumSchema(StringSchema(ByteArraySchema(None))))
+            }
+            SchemaIncomplete::List(element) => ValueSchema::List(Box::new(ListSchema(
+                element.resolve()?,
+                ListSchemaType::List(None, None),
+            ))),
+            SchemaIncomplete::Map(key, value) => ValueSchema::Map(Box::new(MapSchema(
+                key.resolve()?,
+                value.resolve()?,
+                None,
+                None,
+                None,
+            ))),
+            SchemaIncomplete::Group {
+                fields,
+                schemas,
+                field_seen,
+                seen,
+            } => {
+                let schemas = schemas
+                    .into_iter()
+                    .zip(field_seen)
+                    .map(|(schema, field_seen)| {
+                        let schema = schema.resolve()?;
+                        Ok(if field_seen < seen {
+                            ValueSchema::Option(Box::new(OptionSchema(schema)))
+                        } else {
+                            schema
+                        })
+                    })
+                    .collect::<Result<Vec<ValueSchema>>>()?;
+                ValueSchema::Group(GroupSchema(schemas, fields))
+            }
+            SchemaIncomplete::Option(inner) => {
+                ValueSchema::Option(Box::new(OptionSchema(inner.resolve()?)))
+            }
+        })
+    }
+}
+
+impl Value {
+    /// Like [`Record::parse`], but uses `hint` — typically built by merging sample
+    /// values via [`SchemaIncomplete::merge`] — to resolve the handful of choices the
+    /// Parquet `Type` alone leaves ambiguous, rather than always falling back to this
+    /// crate's default. Currently this is just the signedness of an unannotated
+    /// `INT32`/`INT64` column (`hint` of `U32`/`U64` picks the unsigned variant instead
+    /// of the default `I32`/`I64`); every other node unifies `schema` with the
+    /// corresponding shape of `hint` and recurses, falling back to [`Record::parse`]'s
+    /// canonical mapping wherever `hint` is `Unknown` or doesn't apply.
+    pub fn parse_incomplete(
+        schema: &Type, repetition: Option<Repetition>, hint: &SchemaIncomplete,
+    ) -> Result<(String, ValueSchema)> {
+        let mut value = None;
+
+        if repetition.is_some() && schema.is_primitive() {
+            value = Some(
+                match (schema.get_physical_type(), schema.get_basic_info().logical_type())
+                {
+                    (PhysicalType::Int32, LogicalType::None)
+                        if matches!(hint, SchemaIncomplete::U32) =>
+                    {
+                        ValueSchema::U32(U32Schema)
+                    }
+                    (PhysicalType::Int64, LogicalType::None)
+                        if matches!(hint, SchemaIncomplete::U64) =>
+                    {
+                        ValueSchema::U64(U64Schema)
+                    }
+                    // No other primitive mapping is ambiguous: the physical/logical
+                    // type pair alone determines the `ValueSchema`, so defer to the
+                    // canonical implementation rather than duplicating it.
+                    _ => Value::parse(schema, Some(Repetition::Required))?.1,
+                },
+            );
+        }
+
+        // Try parsing as a list, unifying the element schema with `hint`'s element if
+        // `hint` is itself a `List`.
+        if repetition.is_some() && value.is_none() {
+            if let Ok(list_schema) = parse_list::<Value>(schema) {
+                let element_hint = match hint {
+                    SchemaIncomplete::List(element) => Some(&**element),
+                    _ => None,
+                };
+                value = Some(ValueSchema::List(Box::new(match element_hint {
+                    Some(element_hint) => ListSchema(
+                        Self::unify_incomplete(&list_schema.0, element_hint)?,
+                        list_schema.1,
+                    ),
+                    None => list_schema,
+                })));
+            }
+        }
+
+        // Try parsing as a map (modern `MAP` encoding, falling back to the legacy
+        // `MAP_KEY_VALUE` encoding). `hint` doesn't narrow which encoding matches: the
+        // parsed key/value schemas are unified with it afterwards via `unify_incomplete`,
+        // same as every other already-parsed node.
+        if repetition.is_some() && value.is_none() {
+            value = parse_map::<Value, Value>(schema)
+                .ok()
+                .or_else(|| parse_map_key_value_legacy::<Value, Value>(schema).ok())
+                .map(|value| ValueSchema::Map(Box::new(value)));
+            if let Some(map) = value.take() {
+                value = Some(Self::unify_incomplete(&map, hint)?);
+            }
+        }
+
+        // Try parsing as a group, unifying each field with `hint`'s same-named field if
+        // `hint` is itself a `Group`.
+        if repetition.is_some() && value.is_none() && schema.is_group() {
+            let hint_fields = match hint {
+                SchemaIncomplete::Group { fields, schemas, .. } => Some((fields, schemas)),
+                _ => None,
+            };
+            let mut lookup = LinkedHashMap::with_capacity_and_hasher(
+                schema.get_fields().len(),
+                Default::default(),
+            );
+            value = Some(ValueSchema::Group(GroupSchema(
+                schema
+                    .get_fields()
+                    .iter()
+                    .map(|field| {
+                        let field_hint = hint_fields
+                            .and_then(|(fields, schemas)| {
+                                fields.get(field.name()).map(|&slot| &schemas[slot])
+                            });
+                        let (name, schema) = match field_hint {
+                            Some(field_hint) => {
+                                let (name, schema) = Value::parse(
+                                    &**field,
+                                    Some(field.get_basic_info().repetition()),
+                                )?;
+                                (name, Self::unify_incomplete(&schema, field_hint)?)
+                            }
+                            None => Value::parse(
+                                &**field,
+                                Some(field.get_basic_info().repetition()),
+                            )?,
+                        };
+                        let x = lookup.insert(name, lookup.len());
+                        assert!(x.is_none());
+                        Ok(schema)
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                lookup,
+            )));
+        }
+
+        let mut value = value.ok_or_else(|| {
+            ParquetError::General(format!("Can't parse value {:?}", schema))
+        })?;
+
+        match repetition.ok_or_else(|| {
+            ParquetError::General(String::from(
+                "Can't parse value: top-level field is missing a repetition",
+            ))
+        })? {
+            Repetition::Optional => {
+                value = ValueSchema::Option(Box::new(OptionSchema(value)));
+            }
+            Repetition::Repeated => {
+                value = ValueSchema::List(Box::new(ListSchema(
+                    value,
+                    ListSchemaType::Repeated,
+                )));
+            }
+            Repetition::Required => (),
+        }
+
+        Ok((schema.name().to_owned(), value))
+    }
+
+    /// Re-derives an already-`Record::parse`d `schema` so that any unannotated
+    /// `I32`/`I64` it or its descendants contain is widened to `U32`/`U64` where `hint`
+    /// constrains it, recursing through `List`/`Map`/`Group`/`Option` in lockstep.
+    fn unify_incomplete(
+        schema: &ValueSchema, hint: &SchemaIncomplete,
+    ) -> Result<ValueSchema> {
+        Ok(match (schema, hint) {
+            (ValueSchema::I32(_), SchemaIncomplete::U32) => ValueSchema::U32(U32Schema),
+            (ValueSchema::I64(_), SchemaIncomplete::U64) => ValueSchema::U64(U64Schema),
+            (ValueSchema::List(list), SchemaIncomplete::List(element)) => {
+                ValueSchema::List(Box::new(ListSchema(
+                    Self::unify_incomplete(&list.0, element)?,
+                    list.1.clone(),
+                )))
+            }
+            (ValueSchema::Map(map), SchemaIncomplete::Map(key, value)) => {
+                ValueSchema::Map(Box::new(MapSchema(
+                    Self::unify_incomplete(&map.0, key)?,
+                    Self::unify_incomplete(&map.1, value)?,
+                    map.2.clone(),
+                    map.3.clone(),
+                    map.4.clone(),
+                )))
+            }
+            (
+                ValueSchema::Group(group),
+                SchemaIncomplete::Group { fields, schemas, .. },
+            ) => ValueSchema::Group(GroupSchema(
+                group
+                    .0
+                    .iter()
+                    .zip(group.1.iter())
+                    .map(|(field_schema, (name, _index))| match fields.get(name) {
+                        Some(&slot) => Self::unify_incomplete(field_schema, &schemas[slot]),
+                        None => Ok(field_schema.clone()),
+                    })
+                    .collect::<Result<Vec<_>>>()?,
+                group.1.clone(),
+            )),
+            (ValueSchema::Option(option), SchemaIncomplete::Option(inner)) => {
+                ValueSchema::Option(Box::new(OptionSchema(Self::unify_incomplete(
+                    &option.0, inner,
+                )?)))
+            }
+            // `hint` doesn't apply to this node (wrong shape, or `Unknown`): keep the
+            // canonical schema as-is.
+            (schema, _) => schema.clone(),
+        })
+    }
+}
+
+impl Value {
+    /// Converts this `Value` into a [`serde_json::Value`], for previewing, logging or
+    /// ingesting decoded Parquet records as JSON.
+    ///
+    /// Numerics map to JSON numbers; `ByteArray`/`Bson` to base64 strings;
+    /// `Date`/`Time`/`Timestamp` to their ISO-8601 string representation; `Group` to a
+    /// JSON object keyed by field name; `List` to an array; `Map` to an object when
+    /// every key is a string, or otherwise to an array of `[key, value]` pairs; and
+    /// `Option(None)` to `null`.
+    pub fn to_json_value(&self) -> JsonValue {
+        match self {
+            Value::Bool(value) => JsonValue::Bool(*value),
+            Value::U8(value) => JsonValue::Number((*value).into()),
+            Value::I8(value) => JsonValue::Number((*value).into()),
+            Value::U16(value) => JsonValue::Number((*value).into()),
+            Value::I16(value) => JsonValue::Number((*value).into()),
+            Value::U32(value) => JsonValue::Number((*value).into()),
+            Value::I32(value) => JsonValue::Number((*value).into()),
+            Value::U64(value) => JsonValue::Number((*value).into()),
+            Value::I64(value) => JsonValue::Number((*value).into()),
+            Value::F32(value) => JsonNumber::from_f64(f64::from(*value))
+                .map(JsonValue::Number)
+                .unwrap_or(JsonValue::Null),
+            Value::F64(value) => JsonNumber::from_f64(*value)
+                .map(JsonValue::Number)
+                .unwrap_or(JsonValue::Null),
+            Value::Date(value) => JsonValue::String(value.to_string()),
+            Value::Time(value) => JsonValue::String(value.to_string()),
+            Value::Timestamp(value) => JsonValue::String(value.to_string()),
+            Value::Decimal(value) => JsonValue::String(value.to_string()),
+            Value::ByteArray(value) => JsonValue::String(base64::encode(value)),
+            Value::Bson(value) => JsonValue::String(base64::encode(value.as_ref())),
+            Value::String(value) => JsonValue::String(value.clone()),
+            Value::Json(value) => JsonValue::String(value.to_string()),
+            Value::Enum(value) => JsonValue::String(value.to_string()),
+            Value::DateTime(value) => JsonValue::String(value.to_string()),
+            Value::DateTimeWithoutTimezone(value) => JsonValue::String(value.to_string()),
+            Value::DateWithoutTimezone(value) => JsonValue::String(value.to_string()),
+            Value::TimeWithoutTimezone(value) => JsonValue::String(value.to_string()),
+            Value::Timezone(value) => JsonValue::String(value.to_string()),
+            Value::IpAddr(value) => JsonValue::String(value.to_string()),
+            Value::Url(value) => JsonValue::String(value.to_string()),
+            Value::Webpage(value) => JsonValue::String(value.to_string()),
+            Value::Interval(value) => JsonValue::String(value.to_string()),
+            Value::List(list) => {
+                JsonValue::Array(list.iter().map(Value::to_json_value).collect())
+            }
+            Value::Map(map) => {
+                if map.0.keys().all(Value::is_string) {
+                    let mut object = JsonMap::with_capacity(map.0.len());
+                    for (k, v) in map.0.iter() {
+                        let _ = object
+                            .insert(k.as_string().unwrap().clone(), v.to_json_value());
+                    }
+                    JsonValue::Object(object)
+                } else {
+                    JsonValue::Array(
+                        map.0
+                            .iter()
+                            .map(|(k, v)| {
+                                JsonValue::Array(vec![k.to_json_value(), v.to_json_value()])
+                            })
+                            .collect(),
+                    )
+                }
+            }
+            Value::Group(group) => {
+                let mut object = JsonMap::with_capacity(group.field_names().len());
+                for (name, field) in group
+                    .field_names()
+                    .iter()
+                    .map(|(name, _index)| name)
+                    .zip(group.clone().into_fields())
+                {
+                    let _ = object.insert(name.clone(), field.to_json_value());
+                }
+                JsonValue::Object(object)
+            }
+            Value::Option(None) => JsonValue::Null,
+            Value::Option(Some(value)) => Value::from(value.clone()).to_json_value(),
+        }
+    }
+
+    /// Parses a [`serde_json::Value`] into a `Value`, using `schema` to disambiguate
+    /// which numeric variant a JSON number becomes and to validate the JSON's
+    /// structure. Errors if the JSON shape can't satisfy `schema`.
+    ///
+    /// Unlike [`to_json_value`](Self::to_json_value), this isn't a full inverse:
+    /// `Bool`, every integer/float variant, `ByteArray` (base64), `String`, `List`,
+    /// `Map`, `Group` and `Option` round-trip, recursively, but `Date`/`Time`/
+    /// `Timestamp`/`Decimal`/`Bson`/`Json`/`Enum`/`IpAddr`/`Url`/`Webpage`/`Interval`
+    /// and the timezone-aware/-less `DateTime` family don't - parsing any of those
+    /// back out of the ISO-8601/decimal/etc. string `to_json_value` renders them as isn't
+    /// implemented here, not even for `IpAddr`/`Url`/`Webpage`, whose `FromStr` impls are
+    /// used when downcasting a `Value::String`. A schema
+    /// requiring one of these errors rather than silently producing the wrong `Value`.
+    pub fn from_json_value(json: &JsonValue, schema: &ValueSchema) -> Result<Value> {
+        let err = || {
+            ParquetError::General(format!(
+                "Can't parse {:?} as a Value matching schema {:?}",
+                json, schema
+            ))
+        };
+        Ok(match schema {
+            ValueSchema::Bool(_) => Value::Bool(json.as_bool().ok_or_else(err)?),
+            ValueSchema::U8(_) => Value::U8(json.as_u64().ok_or_else(err)?.try_into().map_err(|_| err())?),
+            ValueSchema::I8(_) => Value::I8(json.as_i64().ok_or_else(err)?.try_into().map_err(|_| err())?),
+            ValueSchema::U16(_) => Value::U16(json.as_u64().ok_or_else(err)?.try_into().map_err(|_| err())?),
+            ValueSchema::I16(_) => Value::I16(json.as_i64().ok_or_else(err)?.try_into().map_err(|_| err())?),
+            ValueSchema::U32(_) => Value::U32(json.as_u64().ok_or_else(err)?.try_into().map_err(|_| err())?),
+            ValueSchema::I32(_) => Value::I32(json.as_i64().ok_or_else(err)?.try_into().map_err(|_| err())?),
+            ValueSchema::U64(_) => Value::U64(json.as_u64().ok_or_else(err)?),
+            ValueSchema::I64(_) => Value::I64(json.as_i64().ok_or_else(err)?),
+            ValueSchema::F32(_) => Value::F32(json.as_f64().ok_or_else(err)? as f32),
+            ValueSchema::F64(_) => Value::F64(json.as_f64().ok_or_else(err)?),
+            ValueSchema::ByteArray(_) => Value::ByteArray(
+                base64::decode(json.as_str().ok_or_else(err)?).map_err(|_| err())?,
+            ),
+            ValueSchema::String(_) => {
+                Value::String(json.as_str().ok_or_else(err)?.to_owned())
+            }
+            ValueSchema::List(list_schema) => {
+                let array = json.as_array().ok_or_else(err)?;
+                Value::List(List(
+                    array
+                        .iter()
+                        .map(|element| Value::from_json_value(element, &list_schema.0))
+                        .collect::<Result<Vec<_>>>()?,
+                ))
+            }
+            ValueSchema::Map(map_schema) => {
+                let mut pairs = Vec::new();
+                match json {
+                    JsonValue::Object(object) => {
+                        for (k, v) in object.iter() {
+                            let key = Value::from_json_value(
+                                &JsonValue::String(k.clone()),
+                                &map_schema.0,
+                            )?;
+                            let value = Value::from_json_value(v, &map_schema.1)?;
+                            pairs.push((key, value));
+                        }
+                    }
+                    JsonValue::Array(array) => {
+                        for pair in array {
+                            let pair = pair.as_array().ok_or_else(err)?;
+                            if pair.len() != 2 {
+                                return Err(err());
+                            }
+                            let key = Value::from_json_value(&pair[0], &map_schema.0)?;
+                            let value = Value::from_json_value(&pair[1], &map_schema.1)?;
+                            pairs.push((key, value));
+                        }
+                    }
+                    _ => return Err(err()),
+                }
+                Value::Map(Map(build_map_with_policy(
+                    pairs,
+                    MapDuplicatePolicy::default(),
+                )?))
+            }
+            ValueSchema::Group(group_schema) => {
+                let object = json.as_object().ok_or_else(err)?;
+                let mut fields = LinkedHashMap::with_capacity_and_hasher(
+                    group_schema.1.len(),
+                    Default::default(),
+                );
+                let values = group_schema
+                    .1
+                    .iter()
+                    .map(|(name, &index)| {
+                        let field_schema = &group_schema.0[index];
+                        let value = object
+                            .get(name)
+                            .map(|json| Value::from_json_value(json, field_schema))
+                            .unwrap_or(Ok(Value::Option(None)))?;
+                        let x = fields.insert(name.clone(), fields.len());
+                        assert!(x.is_none());
+                        Ok(value)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Value::Group(Group::new(values, std::sync::Arc::new(fields)))
+            }
+            ValueSchema::Option(option_schema) => match json {
+                JsonValue::Null => Value::Option(None),
+                json => Value::from(Some(Value::from_json_value(json, &option_schema.0)?)),
+            },
+            _ => return Err(ParquetError::General(format!(
+                "from_json_value: schema {:?} is not yet supported",
+                schema
+            ))),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `precision`/`scale`/`type_length` ultimately come from a Thrift-decoded schema
+    // footer, so a negative or out-of-range value here stands in for a malformed or
+    // adversarial file rather than anything the `Type` builder would let you construct.
+
+    #[test]
+    fn decimal_precision_scale_accepts_valid_values() {
+        assert_eq!(decimal_precision_scale(9, 2).unwrap(), (9, 2));
+        assert_eq!(decimal_precision_scale(0, 0).unwrap(), (0, 0));
+    }
+
+    #[test]
+    fn decimal_precision_scale_rejects_negative_precision() {
+        assert!(decimal_precision_scale(-1, 2).is_err());
+    }
+
+    #[test]
+    fn decimal_precision_scale_rejects_negative_scale() {
+        assert!(decimal_precision_scale(9, -1).is_err());
+    }
+
+    #[test]
+    fn fixed_len_byte_array_length_accepts_valid_value() {
+        assert_eq!(fixed_len_byte_array_length(12).unwrap(), 12);
+    }
+
+    #[test]
+    fn fixed_len_byte_array_length_rejects_negative_value() {
+        assert!(fixed_len_byte_array_length(-1).is_err());
+    }
+
+    #[test]
+    fn parquet_error_eq_compares_by_display() {
+        let a = ParquetError::General(String::from("broken schema"));
+        let b = ParquetError::General(String::from("broken schema"));
+        let c = ParquetError::General(String::from("different"));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn value_f64_orders_negative_zero_below_zero_and_eq_agrees_with_cmp() {
+        let neg_zero = Value::F64(-0.0);
+        let zero = Value::F64(0.0);
+        assert_ne!(neg_zero, zero);
+        assert_eq!(neg_zero.cmp(&zero), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn value_f64_eq_is_reflexive_for_nan_and_agrees_with_cmp() {
+        let nan = Value::F64(f64::NAN);
+        assert_eq!(nan, nan.clone());
+        assert_eq!(nan.cmp(&nan.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn value_f64_hashset_and_btreeset_agree_on_nan_dedup() {
+        use std::collections::{BTreeSet, HashSet};
+        let values = vec![Value::F64(f64::NAN), Value::F64(f64::NAN), Value::F64(0.0)];
+        let hash_set: HashSet<_> = values.iter().cloned().collect();
+        let btree_set: BTreeSet<_> = values.into_iter().collect();
+        assert_eq!(hash_set.len(), 2);
+        assert_eq!(btree_set.len(), 2);
+    }
+}